@@ -1,9 +1,9 @@
-use gomokurs_cli::{configuration::{cli::Cli, player_configuration::PlayerConfiguration}, player_interface::create_player_interface_from_cfg};
+use gomokurs_cli::{configuration::{cli::{Cli, Command, TournamentArgs}, player_configuration::PlayerConfiguration}, player_interface::{create_player_interface_from_cfg, ConfigurationEntrantFactory}};
 use clap::Parser;
-use gomokurs_game_engine::domain::game_engine::{GameEngine, models::BoardSize};
+use gomokurs_game_engine::domain::game_engine::{GameEngine, CreateGameEngineConfiguration, models::{BoardSize, Rules}};
 use gomokurs_coordinator::domain::coordinator::{CreateCoordinatorConfiguration, Coordinator, CoordinatorService};
-use gomokurs_coordinator::domain::coordinator::models::Mode;
-use std::{str::FromStr, sync::Arc};
+use gomokurs_coordinator::domain::tournament::{CreateTournamentConfiguration, Tournament, TournamentService, models::Entrant};
+use std::{path::Path, str::FromStr, sync::Arc};
 use tokio::time::Duration;
 
 #[tokio::main]
@@ -31,7 +31,47 @@ async fn main() {
     let subscriber = tracing_subscriber::fmt().with_max_level(log_level).finish();
     let _ = tracing::subscriber::set_global_default(subscriber);
 
-    let black_cfg = match PlayerConfiguration::new(&cli.black_file) {
+    let game_engine_cfg = CreateGameEngineConfiguration{
+        board_size: BoardSize{ x: cli.width, y: cli.height },
+        turn_duration: Duration::from_secs(cli.turn_duration),
+        match_duration: Duration::from_secs(cli.match_duration),
+        rules: Rules {
+            rule_set: cli.rule_set,
+            forbidden_move_policy: cli.forbidden_move_policy,
+            win_length: cli.win_length,
+            exact_length: cli.exact_length,
+            caro: cli.caro,
+            opening_restriction: cli.opening_restriction,
+        },
+        max_moves: cli.max_moves,
+        time_control: cli.time_control,
+        byo_yomi: cli.byo_yomi,
+    };
+
+    match (&cli.command, &cli.black_file, &cli.white_file) {
+        (Some(Command::Tournament(args)), _, _) => run_tournament(&cli, args, game_engine_cfg).await,
+        (None, Some(black_file), Some(white_file)) => run_match(&cli, black_file, white_file, game_engine_cfg).await,
+        // Both player files are required by the parser without a subcommand.
+        (None, _, _) => unreachable!(),
+    }
+}
+
+async fn run_match(
+    cli: &Cli,
+    black_file: &Path,
+    white_file: &Path,
+    game_engine_cfg: CreateGameEngineConfiguration,
+) {
+    let game_engine = match GameEngine::new(game_engine_cfg) {
+        Ok(game_engine) => game_engine,
+        Err(e) => {
+            tracing::error!("failed to create game engine: {}", e);
+
+            return
+        }
+    };
+
+    let black_cfg = match PlayerConfiguration::new(black_file) {
         Ok(cfg) => cfg,
         Err(e) => {
             tracing::error!("failed to read black player configuration file: {}", e);
@@ -40,7 +80,7 @@ async fn main() {
         }
     };
 
-    let white_cfg = match PlayerConfiguration::new(&cli.white_file) {
+    let white_cfg = match PlayerConfiguration::new(white_file) {
         Ok(cfg) => cfg,
         Err(e) => {
             tracing::error!("failed to read white player configuration file: {}", e);
@@ -49,6 +89,8 @@ async fn main() {
         }
     };
 
+    let (black_folder, white_folder) = (black_cfg.folder.clone(), white_cfg.folder.clone());
+
     let black_player = match create_player_interface_from_cfg(black_cfg).await {
         Ok(interface) => Arc::new(interface),
         Err(e) => {
@@ -69,19 +111,72 @@ async fn main() {
     };
     tracing::debug!("created white player interface");
 
-    let freestyle_gomoku = GameEngine::new(
-        BoardSize{ x: 20, y: 20 },
-        Duration::from_secs(cli.turn_duration),
-        Duration::from_secs(cli.match_duration),
-    );
-
     let coordinator_cfg = CreateCoordinatorConfiguration{
-        game_engine: freestyle_gomoku,
+        game_engine,
         black_player_interface: black_player,
         white_player_interface: white_player,
-        game_mode: Mode::Loop,
+        game_mode: cli.mode,
+        opening: cli.opening,
+        illegal_move_policy: cli.illegal_move_policy,
+        game_type: cli.game_type,
+        max_memory: cli.max_memory,
+        black_player_folder: black_folder,
+        white_player_folder: white_folder,
+        startup_timeout: Duration::from_secs(cli.startup_timeout),
+        alternate_colors: cli.alternate_colors,
     };
 
     let mut coordinator = Coordinator::new(coordinator_cfg);
-    let _ = coordinator.run().await.unwrap();
+    match coordinator.run().await {
+        Ok(summary) => tracing::info!("match summary: {}", summary),
+        Err(e) => tracing::error!("game coordination failed: {}", e),
+    }
+}
+
+async fn run_tournament(
+    cli: &Cli,
+    args: &TournamentArgs,
+    game_engine_cfg: CreateGameEngineConfiguration,
+) {
+    let mut configurations = Vec::new();
+    let mut entrants: Vec<Entrant> = Vec::new();
+
+    for (index, file) in args.player_files.iter().enumerate() {
+        let cfg = match PlayerConfiguration::new(file) {
+            Ok(cfg) => cfg,
+            Err(e) => {
+                tracing::error!("failed to read player configuration file `{}`: {}", file.display(), e);
+
+                return
+            }
+        };
+
+        let mut name = cfg.name.clone().unwrap_or_else(|| {
+            file.file_stem().map_or_else(|| file.display().to_string(), |stem| stem.to_string_lossy().into_owned())
+        });
+        if entrants.iter().any(|entrant| entrant.name == name) {
+            name = format!("{}#{}", name, index + 1);
+        }
+
+        entrants.push(Entrant { name, folder: cfg.folder.clone() });
+        configurations.push(cfg);
+    }
+
+    let mut tournament = Tournament::new(CreateTournamentConfiguration{
+        entrants,
+        entrant_factory: ConfigurationEntrantFactory { configurations },
+        format: args.format,
+        games_per_pairing: args.games_per_pairing,
+        game_engine: game_engine_cfg,
+        opening: cli.opening,
+        illegal_move_policy: cli.illegal_move_policy,
+        game_type: cli.game_type,
+        max_memory: cli.max_memory,
+        startup_timeout: Duration::from_secs(cli.startup_timeout),
+    });
+
+    match tournament.run().await {
+        Ok(report) => println!("{}", report),
+        Err(e) => tracing::error!("tournament failed: {}", e),
+    }
 }
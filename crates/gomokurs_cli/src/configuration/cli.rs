@@ -1,13 +1,30 @@
 use std::path::PathBuf;
-use clap::Parser;
+use clap::{Args, Parser, Subcommand};
+use gomokurs_game_engine::domain::game_engine::models::{MAX_BOARD_SIZE, RuleSet, ForbiddenMovePolicy, OpeningRestriction, TimeControl, ByoYomi};
+use gomokurs_coordinator::domain::coordinator::models::{GameType, IllegalMovePolicy, Mode, Opening};
+use gomokurs_coordinator::domain::tournament::models::Format;
 
 #[derive(Parser, Debug, Clone)]
+#[command(subcommand_negates_reqs = true)]
 pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[arg(long)]
+    #[arg(required = true)]
+    pub black_file: Option<PathBuf>,
+
     #[arg(long)]
-    pub black_file: PathBuf,
+    #[arg(required = true)]
+    pub white_file: Option<PathBuf>,
 
     #[arg(long)]
-    pub white_file: PathBuf,
+    #[arg(default_value="20", value_parser = clap::value_parser!(u8).range(1..=MAX_BOARD_SIZE as i64))]
+    pub width: u8,
+
+    #[arg(long)]
+    #[arg(default_value="20", value_parser = clap::value_parser!(u8).range(1..=MAX_BOARD_SIZE as i64))]
+    pub height: u8,
 
     #[arg(short, long)]
     #[arg(default_value="30")]
@@ -17,9 +34,90 @@ pub struct Cli {
     #[arg(default_value="180")]
     pub match_duration: u64,
 
+    #[arg(long)]
+    #[arg(default_value="30")]
+    pub startup_timeout: u64,
+
+    #[arg(long)]
+    #[arg(default_value="none")]
+    pub time_control: TimeControl,
+
+    #[arg(long)]
+    pub byo_yomi: Option<ByoYomi>,
+
+    #[arg(long)]
+    #[arg(default_value="freestyle")]
+    pub rule_set: RuleSet,
+
+    #[arg(long)]
+    #[arg(default_value="reject")]
+    pub forbidden_move_policy: ForbiddenMovePolicy,
+
+    #[arg(long)]
+    #[arg(default_value="5", value_parser = clap::value_parser!(u8).range(1..))]
+    pub win_length: u8,
+
+    #[arg(long, alias = "exact-five")]
+    pub exact_length: bool,
+
+    #[arg(long)]
+    pub caro: bool,
+
+    #[arg(long)]
+    #[arg(default_value="none")]
+    pub opening_restriction: OpeningRestriction,
+
+    #[arg(long)]
+    pub max_moves: Option<usize>,
+
+    #[arg(long)]
+    #[arg(default_value="standard")]
+    pub opening: Opening,
+
+    #[arg(long)]
+    #[arg(default_value="forfeit")]
+    pub illegal_move_policy: IllegalMovePolicy,
+
+    #[arg(long)]
+    #[arg(default_value="loop")]
+    pub mode: Mode,
+
+    #[arg(long)]
+    pub alternate_colors: bool,
+
+    #[arg(long)]
+    #[arg(default_value="brain")]
+    pub game_type: GameType,
+
+    #[arg(long)]
+    #[arg(default_value="0")]
+    pub max_memory: u64,
+
     #[arg(long)]
     #[arg(default_value="INFO")]
     pub log_level: String,
 }
 
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Plays a tournament between many engines, with the game settings given
+    /// before the subcommand.
+    Tournament(TournamentArgs),
+}
+
+#[derive(Args, Debug, Clone)]
+pub struct TournamentArgs {
+    #[arg(long)]
+    #[arg(required = true, num_args = 2..)]
+    pub player_files: Vec<PathBuf>,
+
+    #[arg(long)]
+    #[arg(default_value="round-robin")]
+    pub format: Format,
+
+    #[arg(long)]
+    #[arg(default_value="2", value_parser = clap::value_parser!(u32).range(1..))]
+    pub games_per_pairing: u32,
+}
+
 
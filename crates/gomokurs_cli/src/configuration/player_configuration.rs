@@ -5,7 +5,9 @@ use std::path::{Path, PathBuf};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct PlayerConfiguration {
+    pub name: Option<String>,
     pub protocol: ProtocolConfiguration,
+    pub folder: Option<PathBuf>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
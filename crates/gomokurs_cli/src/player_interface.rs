@@ -2,6 +2,8 @@ use gomokurs_coordinator::adapters::player_interfaces::local::{LocalPlayerInterf
 use gomokurs_coordinator::adapters::player_interfaces::tcp::{CreateTcpPlayerInterfaceConfiguration, TcpPlayerInterface, CreateTcpPlayerInterfaceError};
 use gomokurs_coordinator::domain::coordinator::ports::PlayerInterface;
 use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::tournament::models::Entrant;
+use gomokurs_coordinator::domain::tournament::ports::EntrantFactory;
 use tokio::sync::mpsc::Sender;
 use tokio::net::{TcpStream, TcpListener};
 use crate::configuration::player_configuration::{PlayerConfiguration, ProtocolConfiguration, TcpConfiguration};
@@ -34,7 +36,7 @@ pub async fn create_player_interface_from_cfg(cfg: PlayerConfiguration) -> Resul
                     let stream = TcpStream::connect(active_tcp_cfg.address).await?;
 
                     let tcp_interface_cfg = CreateTcpPlayerInterfaceConfiguration{
-                        stream: stream,
+                        stream,
                     };
 
                     Ok(PlayerInterfaceOption::Tcp(TcpPlayerInterface::new(tcp_interface_cfg).await?))
@@ -46,7 +48,7 @@ pub async fn create_player_interface_from_cfg(cfg: PlayerConfiguration) -> Resul
                     let (stream, _) = listener.accept().await?;
 
                     let tcp_interface_cfg = CreateTcpPlayerInterfaceConfiguration{
-                        stream: stream,
+                        stream,
                     };
 
                     Ok(PlayerInterfaceOption::Tcp(TcpPlayerInterface::new(tcp_interface_cfg).await?))
@@ -56,6 +58,25 @@ pub async fn create_player_interface_from_cfg(cfg: PlayerConfiguration) -> Resul
     }
 }
 
+/// Creates the interfaces of the entrants of a tournament from their
+/// configurations, given in the order of the entrants.
+#[derive(Debug, Clone)]
+pub struct ConfigurationEntrantFactory {
+    pub configurations: Vec<PlayerConfiguration>,
+}
+
+impl EntrantFactory for ConfigurationEntrantFactory {
+    type Interface = PlayerInterfaceOption;
+
+    async fn create_interface(
+        &self,
+        index: usize,
+        _entrant: &Entrant,
+    ) -> Result<PlayerInterfaceOption, anyhow::Error> {
+        Ok(create_player_interface_from_cfg(self.configurations[index].clone()).await?)
+    }
+}
+
 // Implement the traits by delegating to the wrapped type
 impl PlayerInterface for PlayerInterfaceOption {
     async fn listen(
@@ -71,7 +92,7 @@ impl PlayerInterface for PlayerInterfaceOption {
 
     async fn notify_start(
         &self,
-        size: u8,
+        size: BoardSize,
     ) -> Result<(), NotifyError> {
         match self {
             PlayerInterfaceOption::Local(local) => local.notify_start(size).await,
@@ -98,6 +119,16 @@ impl PlayerInterface for PlayerInterfaceOption {
         }
     }
 
+    async fn notify_takeback(
+        &self,
+        position: Position,
+    ) -> Result<(), NotifyError> {
+        match self {
+            PlayerInterfaceOption::Local(local) => local.notify_takeback(position).await,
+            PlayerInterfaceOption::Tcp(tcp)     => tcp.notify_takeback(position).await,
+        }
+    }
+
     async fn notify_begin(
         &self,
     ) -> Result<(), NotifyError> {
@@ -117,6 +148,16 @@ impl PlayerInterface for PlayerInterfaceOption {
         }
     }
 
+    async fn notify_swap2_board(
+        &self,
+        stones: Vec<Position>,
+    ) -> Result<(), NotifyError> {
+        match self {
+            PlayerInterfaceOption::Local(local) => local.notify_swap2_board(stones).await,
+            PlayerInterfaceOption::Tcp(tcp)     => tcp.notify_swap2_board(stones).await,
+        }
+    }
+
     async fn notify_info(
         &self,
         info: Information,
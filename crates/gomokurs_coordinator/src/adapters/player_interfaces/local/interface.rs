@@ -71,7 +71,7 @@ impl PlayerInterface for LocalPlayerInterface {
                 locked_reader.next_line()
                     .await
                     .map_err(|e| anyhow!(e))?
-                    .expect("self.reader.next_line() results is None")
+                    .ok_or(ListenError::Disconnected)?
             };
 
             match parse_input(line) {
@@ -87,13 +87,19 @@ impl PlayerInterface for LocalPlayerInterface {
 
     async fn notify_start(
         &self,
-        size: u8,
+        size: BoardSize,
     ) -> Result<(), NotifyError>
     {
         let mut writer = self.writer.lock().await;
 
+        let command = if size.x == size.y {
+            format!("START {}\n", size.x)
+        } else {
+            format!("RECTSTART {},{}\n", size.x, size.y)
+        };
+
         writer
-            .write_all(format!("START {}\n", size).as_bytes())
+            .write_all(command.as_bytes())
             .await
             .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
@@ -143,6 +149,26 @@ impl PlayerInterface for LocalPlayerInterface {
         Ok(())
     }
 
+    async fn notify_takeback(
+        &self,
+        position: Position,
+    ) -> Result<(), NotifyError>
+    {
+        let mut writer = self.writer.lock().await;
+
+        writer
+            .write_all(format!("TAKEBACK {}\n", position).as_bytes())
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        Ok(())
+    }
+
     async fn notify_begin(
         &self,
     ) -> Result<(), NotifyError>
@@ -181,6 +207,38 @@ impl PlayerInterface for LocalPlayerInterface {
             .await
             .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
+        writer.flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        Ok(())
+    }
+
+    async fn notify_swap2_board(
+        &self,
+        stones: Vec<Position>,
+    ) -> Result<(), NotifyError>
+    {
+        let mut writer = self.writer.lock().await;
+
+        writer.write_all(b"SWAP2BOARD\n")
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        for stone in stones {
+            writer.write_all(format!("{}\n", stone).as_bytes())
+                .await
+                .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+        }
+
+        writer.write_all(b"DONE\n")
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        writer.flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
         Ok(())
     }
 
@@ -231,7 +289,15 @@ impl PlayerInterface for LocalPlayerInterface {
     {
         let mut writer = self.writer.lock().await;
 
-        let _ = writer.write_all(b"END");
+        writer
+            .write_all(b"END\n")
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
         Ok(())
     }
@@ -242,7 +308,15 @@ impl PlayerInterface for LocalPlayerInterface {
     {
         let mut writer = self.writer.lock().await;
 
-        let _ = writer.write_all(b"ABOUT");
+        writer
+            .write_all(b"ABOUT\n")
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
         Ok(())
     }
@@ -254,7 +328,15 @@ impl PlayerInterface for LocalPlayerInterface {
     {
         let mut writer = self.writer.lock().await;
 
-        let _ = writer.write_all(format!("UNKNOWN {}", content).as_bytes());
+        writer
+            .write_all(format!("UNKNOWN {}\n", content).as_bytes())
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
         Ok(())
     }
@@ -266,7 +348,15 @@ impl PlayerInterface for LocalPlayerInterface {
     {
         let mut writer = self.writer.lock().await;
 
-        let _ = writer.write_all(format!("ERROR {}", content).as_bytes());
+        writer
+            .write_all(format!("ERROR {}\n", content).as_bytes())
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        writer
+            .flush()
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
         Ok(())
     }
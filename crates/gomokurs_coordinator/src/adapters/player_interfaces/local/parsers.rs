@@ -31,6 +31,10 @@ pub fn parse_input(input: String) -> Result<PlayerAction, ParseInputError> {
             .expect("failed to initiate debug command regex!");
         static ref RE_SGT: Regex = Regex::new(r"SUGGEST .*")
             .expect("failed to initiate suggestion command regex!");
+        static ref RE_SWAP: Regex = Regex::new(r"^SWAP$")
+            .expect("failed to initiate swap command regex!");
+        static ref RE_STONES: Regex = Regex::new(r"^\d+,\d+(?:\s+\d+,\d+)+$")
+            .expect("failed to initiate stones placement command regex!");
     }
 
     if RE_OK.is_match(&input) {
@@ -40,6 +44,16 @@ pub fn parse_input(input: String) -> Result<PlayerAction, ParseInputError> {
             .map_err(|e| anyhow!(e))?;
 
         Ok(PlayerAction::Play(position))
+    } else if RE_SWAP.is_match(&input) {
+        Ok(PlayerAction::Swap)
+    } else if RE_STONES.is_match(&input) {
+        let positions = input
+            .split_whitespace()
+            .map(parse_position)
+            .collect::<Result<Vec<Position>, ParsePositionError>>()
+            .map_err(|e| anyhow!(e))?;
+
+        Ok(PlayerAction::PlaceStones(positions))
     } else if RE_DESC.is_match(&input) {
         let metadata = parse_player_metadata(&input);
 
@@ -70,6 +84,7 @@ pub fn parse_input(input: String) -> Result<PlayerAction, ParseInputError> {
 }
 
 #[derive(Debug, Error)]
+#[allow(clippy::enum_variant_names)]
 enum ParsePositionError {
     #[error("regular expression failed to compile")]
     InvalidRegex(#[from] regex::Error),
@@ -102,7 +117,7 @@ fn parse_player_metadata(
     s: &str
 ) -> PlayerMetadata {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r#"([\w\-\_]+="([^"]*)""#)
+        static ref RE: Regex = Regex::new(r#"([\w\-\_]+)="([^"]*)""#)
             .expect("About info regular expression failed to initiate itself!");
     }
 
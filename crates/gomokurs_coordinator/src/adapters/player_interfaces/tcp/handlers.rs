@@ -28,7 +28,7 @@ impl TcpPlayerInterface {
         let info = HashMap::new();
 
         Ok(PlayerAction::Metadata(PlayerMetadata{
-            info: info,
+            info,
         }))
     }
 
@@ -97,4 +97,28 @@ impl TcpPlayerInterface {
 
         Ok(PlayerAction::Suggestion(Position::new(buf[0], buf[1])))
     }
+
+    pub async fn swap_handler(
+        &self,
+    ) -> Result<PlayerAction, tokio::io::Error> {
+        Ok(PlayerAction::Swap)
+    }
+
+    pub async fn place_stones_handler(
+        &self,
+    ) -> Result<PlayerAction, tokio::io::Error> {
+        let mut reader = self.reader.lock().await;
+
+        let mut buf = [0u8; 1];
+        reader.read_exact(&mut buf).await?;
+        let nb_stones = buf[0] as usize;
+
+        let mut buf = vec![0u8; nb_stones * 2];
+        reader.read_exact(&mut buf).await?;
+
+        Ok(PlayerAction::PlaceStones(buf
+            .chunks_exact(2)
+            .map(|stone| Position::new(stone[0], stone[1]))
+            .collect()))
+    }
 }
@@ -53,7 +53,7 @@ impl TcpPlayerInterface {
             } else {
                 let err = CreateTcpPlayerInterfaceError::IncompatibleProtocolError {
                     manager_version: String::from(PROTOCOL_VERSION),
-                    player_version: player_version
+                    player_version
                 };
                 let err_msg = format!("{}", err);
                 let bytes_error_msg = err_msg.as_bytes();
@@ -68,7 +68,7 @@ impl TcpPlayerInterface {
                 return Err(err)
             }
         } else {
-            let error_msg = format!("unexpected version");
+            let error_msg = "unexpected version".to_string();
             let bytes_error_msg = error_msg.as_bytes();
             let bytes_error_msg_len: &[u8] = &(bytes_error_msg.len() as u32).to_be_bytes();
 
@@ -100,7 +100,10 @@ impl PlayerInterface for TcpPlayerInterface {
 
             reader.read_exact(&mut buf)
                 .await
-                .map_err(|e| ListenError::Unknown(anyhow!(e)))?;
+                .map_err(|e| match e.kind() {
+                    std::io::ErrorKind::UnexpectedEof => ListenError::Disconnected,
+                    _ => ListenError::Unknown(anyhow!(e)),
+                })?;
 
             std::mem::drop(reader);
             
@@ -129,6 +132,12 @@ impl PlayerInterface for TcpPlayerInterface {
                 ActionID::PLAYER_SUGGESTION => self.suggestion_handler()
                     .await
                     .map_err(|e| ListenError::Unknown(anyhow!(e)))?,
+                ActionID::PLAYER_SWAP => self.swap_handler()
+                    .await
+                    .map_err(|e| ListenError::Unknown(anyhow!(e)))?,
+                ActionID::PLAYER_PLACE_STONES => self.place_stones_handler()
+                    .await
+                    .map_err(|e| ListenError::Unknown(anyhow!(e)))?,
                 _ => continue,
             };
 
@@ -138,13 +147,19 @@ impl PlayerInterface for TcpPlayerInterface {
 
     async fn notify_start(
         &self,
-        size: u8,
+        size: BoardSize,
     ) -> Result<(), NotifyError>
     {
         let mut writer = self.writer.lock().await;
 
+        let data = if size.x == size.y {
+            vec![ActionID::MANAGER_START, size.x]
+        } else {
+            vec![ActionID::MANAGER_RECTSTART, size.x, size.y]
+        };
+
         writer
-            .write_all(&[ActionID::MANAGER_START, size])
+            .write_all(&data)
             .await
             .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
         
@@ -179,6 +194,21 @@ impl PlayerInterface for TcpPlayerInterface {
         Ok(())
     }
 
+    async fn notify_takeback(
+        &self,
+        position: Position,
+    ) -> Result<(), NotifyError>
+    {
+        let mut writer = self.writer.lock().await;
+
+        writer
+            .write_all(&[ActionID::MANAGER_TAKEBACK, position.x, position.y])
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        Ok(())
+    }
+
     async fn notify_begin(
         &self,
     ) -> Result<(), NotifyError>
@@ -200,15 +230,17 @@ impl PlayerInterface for TcpPlayerInterface {
     {
         let mut writer = self.writer.lock().await;
 
+        let nb_turns: &[u8] = &(turns.len() as u32).to_be_bytes();
+
         writer
-            .write_all(&[ActionID::MANAGER_BOARD])
+            .write_all(&[&[ActionID::MANAGER_BOARD], nb_turns].concat())
             .await
             .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
 
         for turn in turns {
             let field = match turn.field {
-                RelativeField::OwnStone => 0,
-                RelativeField::OpponentStone => 1,
+                RelativeField::OwnStone => 1,
+                RelativeField::OpponentStone => 2,
             };
 
             writer
@@ -220,6 +252,30 @@ impl PlayerInterface for TcpPlayerInterface {
         Ok(())
     }
 
+    async fn notify_swap2_board(
+        &self,
+        stones: Vec<Position>,
+    ) -> Result<(), NotifyError>
+    {
+        let mut writer = self.writer.lock().await;
+
+        let data = [
+            &[ActionID::MANAGER_SWAP2BOARD, stones.len() as u8],
+            stones
+                .iter()
+                .flat_map(|stone| [stone.x, stone.y])
+                .collect::<Vec<u8>>()
+                .as_slice(),
+        ].concat();
+
+        writer
+            .write_all(&data)
+            .await
+            .map_err(|e| NotifyError::Unknown(anyhow!(e)))?;
+
+        Ok(())
+    }
+
     async fn notify_info(
         &self,
         info: Information,
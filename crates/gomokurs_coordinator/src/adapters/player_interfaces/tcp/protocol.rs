@@ -1,4 +1,4 @@
-pub const PROTOCOL_VERSION: &str            = "0.2.0";
+pub const PROTOCOL_VERSION: &str            = "0.5.0";
 
 #[non_exhaustive]
 pub struct ActionID;
@@ -31,4 +31,18 @@ impl ActionID {
     pub const PLAYER_MESSAGE: u8                = 0x12;
     pub const PLAYER_DEBUG: u8                  = 0x13;
     pub const PLAYER_SUGGESTION: u8             = 0x14;
+
+    // Actions of the Swap2 opening.
+
+    pub const MANAGER_SWAP2BOARD: u8            = 0x15;
+    pub const PLAYER_SWAP: u8                   = 0x16;
+    pub const PLAYER_PLACE_STONES: u8           = 0x17;
+
+    // Actions of rectangular boards.
+
+    pub const MANAGER_RECTSTART: u8             = 0x18;
+
+    // Actions of move takebacks.
+
+    pub const MANAGER_TAKEBACK: u8              = 0x19;
 }
@@ -4,3 +4,4 @@
 //! according to Hexagonal Architecture.
 
 pub mod coordinator;
+pub mod tournament;
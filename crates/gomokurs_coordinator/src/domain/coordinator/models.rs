@@ -1,7 +1,15 @@
 pub mod actions;
+pub mod command;
 pub mod options;
+pub mod swap2;
 pub mod error;
+pub mod result;
+pub mod summary;
 
 pub use actions::*;
+pub use command::*;
 pub use options::*;
+pub use swap2::*;
 pub use error::*;
+pub use result::*;
+pub use summary::*;
@@ -1,4 +1,4 @@
-pub use gomokurs_game_engine::domain::game_engine::models::{PlayerColor, Position, GameEnd, Error as GameEngineError};
+pub use gomokurs_game_engine::domain::game_engine::models::{PlayerColor, Position, BoardSize, GameEnd, DrawReason, WinReason, WinningLine, TimeoutKind, Rules, ForbiddenMovePolicy, Error as GameEngineError};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::fmt;
@@ -26,6 +26,10 @@ pub enum PlayerAction {
     Debug(String),
     /// Suggests a move to the manager.
     Suggestion(Position),
+    /// Chooses to play black during a Swap2 opening.
+    Swap,
+    /// Places several stones at once during a Swap2 opening.
+    PlaceStones(Vec<Position>),
 }
 
 /// Metadata about a player as a collection of key-value pairs.
@@ -0,0 +1,15 @@
+//! Models of the commands given to the coordinator from outside the game.
+
+use crate::domain::coordinator::models::actions::PlayerColor;
+
+/// A command given to the coordinator while it runs, taken into account
+/// between the actions of the players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Takes back the last move of the current game, its player having to
+    /// play again.
+    Takeback,
+    /// Ends the current game with the resignation of the player of the
+    /// given color.
+    Resign(PlayerColor),
+}
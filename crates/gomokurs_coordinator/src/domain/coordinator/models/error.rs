@@ -4,8 +4,9 @@
 //! `PlayerInterfacesManager`, a domain service responsible for managing player
 //! listeners and handling player actions.
 
-use crate::domain::coordinator::models::actions::{PlayerColor, GameEngineError};
+use crate::domain::coordinator::models::actions::{PlayerColor, Position, BoardSize, GameEngineError};
 use tokio::task::JoinError;
+use tokio::time::Duration;
 use thiserror::Error;
 
 /// Errors returned by the `PlayerInterfacesManager` service.
@@ -19,14 +20,44 @@ pub enum Error {
     #[error("listeners join set error: `{0}`")]
     JoinError(#[from] JoinError),
     /// An error was returned by a player listener.
-    #[error("listener error: `{0}`")]
-    ListenError(#[from] ListenError),
+    #[error("failed to listen to `{color}`: `{error}`")]
+    ListenError{
+        error: ListenError,
+        color: PlayerColor,
+    },
     /// Error encountered while notifying a player.
     #[error("failed to notify `{color}`: `{error}`")]
     NotifyError{
         error: NotifyError,
         color: PlayerColor,
     },
+    /// A player answered the start of a game with an error, usually
+    /// because it does not support the board size or the rules.
+    #[error("`{color}` refused to start a game on a `{}x{}` board: `{content}`", size.x, size.y)]
+    StartRejected{
+        color: PlayerColor,
+        size: BoardSize,
+        content: String,
+    },
+    /// A player was not ready to play before the startup timeout.
+    #[error("`{color}` was not ready after `{}` seconds", timeout.as_secs_f64())]
+    StartupTimeout{
+        color: PlayerColor,
+        timeout: Duration,
+    },
+    /// A player crashed or disconnected before a game began.
+    #[error("`{color}` failed before the game began: `{reason}`")]
+    StartupCrash{
+        color: PlayerColor,
+        reason: String,
+    },
+    /// A player attempted a move the game engine refused.
+    #[error("`{color}` played an illegal move at `{position}`: `{error}`")]
+    IllegalMove{
+        color: PlayerColor,
+        position: Position,
+        error: GameEngineError,
+    },
     /// An error propagated from the game manager.
     #[error("game error: `{0}`")]
     GameError(#[from] GameEngineError),
@@ -35,11 +66,30 @@ pub enum Error {
     Unknown(#[from] anyhow::Error),
 }
 
+impl Error
+{
+    /// Turns a player crashing or disconnecting into a failure to start a
+    /// game, which no player loses since it never began.
+    pub fn into_startup_failure(
+        self,
+    ) -> Self
+    {
+        match self {
+            Error::ListenError { error, color } => Error::StartupCrash { color, reason: error.to_string() },
+            Error::NotifyError { error, color } => Error::StartupCrash { color, reason: error.to_string() },
+            error => error,
+        }
+    }
+}
+
 /// Errors returned by a player listener.
 ///
 /// A `PlayerListener` is an adapter for interfacing with individual players.
 #[derive(Debug, Error)]
 pub enum ListenError {
+    /// The player closed its connection.
+    #[error("player disconnected")]
+    Disconnected,
     /// For implementation-specific error.
     #[error(transparent)]
     Unknown(#[from] anyhow::Error),
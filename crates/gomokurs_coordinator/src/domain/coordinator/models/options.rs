@@ -1,5 +1,193 @@
-#[derive(Debug, Clone)]
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Defines how many games the coordinator plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Mode {
+    /// A single game is played.
     SingleGame,
+    /// Games are played until a player can no longer play.
     Loop,
+    /// The given number of games is played.
+    Match(u32),
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Mode::SingleGame => write!(f, "single"),
+            Mode::Loop => write!(f, "loop"),
+            Mode::Match(games) => write!(f, "match:{}", games),
+        }
+    }
+}
+
+impl FromStr for Mode {
+    type Err = ParseOptionError;
+
+    /// Parses `single`, `loop`, or `match:N` where `N` is the number of
+    /// games.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+
+        match lowercase.split_once(':') {
+            None if lowercase == "single" => Ok(Mode::SingleGame),
+            None if lowercase == "loop" => Ok(Mode::Loop),
+            Some(("match", games)) => games.parse()
+                .ok()
+                .filter(|games| *games > 0)
+                .map(Mode::Match)
+                .ok_or_else(|| ParseOptionError::UnknownMode(s.to_string())),
+            _ => Err(ParseOptionError::UnknownMode(s.to_string())),
+        }
+    }
+}
+
+/// Defines how the first stones of a game are placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Opening {
+    /// The black player freely plays the first move.
+    #[default]
+    Standard,
+    /// The first player places three stones, then the second player either
+    /// chooses a color or places two more stones and lets the first player
+    /// choose.
+    Swap2,
+}
+
+impl fmt::Display for Opening {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Opening::Standard => write!(f, "standard"),
+            Opening::Swap2 => write!(f, "swap2"),
+        }
+    }
+}
+
+impl FromStr for Opening {
+    type Err = ParseOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "standard" => Ok(Opening::Standard),
+            "swap2" => Ok(Opening::Swap2),
+            _ => Err(ParseOptionError::UnknownOpening(s.to_string())),
+        }
+    }
+}
+
+/// Defines how the coordinator treats a player attempting an illegal move.
+///
+/// Forbidden moves of black under renju rules follow the forbidden move
+/// policy of the rules instead: a rejected one is always played again, and
+/// does not count against the retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IllegalMovePolicy {
+    /// The player loses the game, as in the Gomocup.
+    #[default]
+    Forfeit,
+    /// The player is sent an error and plays again, up to the given number
+    /// of times per game before losing it.
+    Retry(u32),
+}
+
+impl fmt::Display for IllegalMovePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            IllegalMovePolicy::Forfeit => write!(f, "forfeit"),
+            IllegalMovePolicy::Retry(retries) => write!(f, "retry:{}", retries),
+        }
+    }
+}
+
+impl FromStr for IllegalMovePolicy {
+    type Err = ParseOptionError;
+
+    /// Parses `forfeit`, or `retry:N` where `N` is the number of retries.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+
+        match lowercase.split_once(':') {
+            None if lowercase == "forfeit" => Ok(IllegalMovePolicy::Forfeit),
+            Some(("retry", retries)) => retries.parse()
+                .map(IllegalMovePolicy::Retry)
+                .map_err(|_| ParseOptionError::UnknownIllegalMovePolicy(s.to_string())),
+            _ => Err(ParseOptionError::UnknownIllegalMovePolicy(s.to_string())),
+        }
+    }
+}
+
+/// Defines the kind of game announced to the players through the Gomocup
+/// `INFO game_type` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameType {
+    /// The opponent is a human.
+    Human,
+    /// The opponent is another program.
+    #[default]
+    Brain,
+    /// The game is part of a tournament.
+    Tournament,
+    /// The game is part of a network tournament.
+    NetworkTournament,
+}
+
+impl GameType {
+    /// Returns the game type as the value sent to players through the
+    /// Gomocup `INFO game_type` command.
+    pub fn as_gomocup_game_type(
+        &self,
+    ) -> u8
+    {
+        match self {
+            GameType::Human => 0,
+            GameType::Brain => 1,
+            GameType::Tournament => 2,
+            GameType::NetworkTournament => 3,
+        }
+    }
+}
+
+impl fmt::Display for GameType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            GameType::Human => write!(f, "human"),
+            GameType::Brain => write!(f, "brain"),
+            GameType::Tournament => write!(f, "tournament"),
+            GameType::NetworkTournament => write!(f, "network-tournament"),
+        }
+    }
+}
+
+impl FromStr for GameType {
+    type Err = ParseOptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "human" => Ok(GameType::Human),
+            "brain" => Ok(GameType::Brain),
+            "tournament" => Ok(GameType::Tournament),
+            "network-tournament" => Ok(GameType::NetworkTournament),
+            _ => Err(ParseOptionError::UnknownGameType(s.to_string())),
+        }
+    }
+}
+
+/// Errors that may occur while parsing coordinator options from their
+/// textual representation.
+#[derive(Debug, Error)]
+pub enum ParseOptionError {
+    /// The mode is not supported by the coordinator.
+    #[error("unknown mode `{0}`, expected `single`, `loop` or `match:N`")]
+    UnknownMode(String),
+    /// The opening is not supported by the coordinator.
+    #[error("unknown opening `{0}`")]
+    UnknownOpening(String),
+    /// The illegal move policy is not supported by the coordinator.
+    #[error("unknown illegal move policy `{0}`, expected `forfeit` or `retry:N`")]
+    UnknownIllegalMovePolicy(String),
+    /// The game type is not defined by the Gomocup protocol.
+    #[error("unknown game type `{0}`")]
+    UnknownGameType(String),
 }
@@ -0,0 +1,235 @@
+//! Models of the result of a coordinated game.
+
+use std::fmt;
+use crate::domain::coordinator::models::actions::{DrawReason, GameEnd, PlayerColor, Position, TimeoutKind, WinReason, WinningLine};
+use crate::domain::coordinator::models::error::Error;
+
+/// The way a game ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Termination
+{
+    /// The winner made a winning row.
+    Five(WinningLine),
+    /// A player exceeded the duration of a turn.
+    TurnTimeout,
+    /// A player exceeded the total duration of the match.
+    MatchTimeout,
+    /// A player exceeded the last byo-yomi period, numbered from one.
+    PeriodTimeout(u32),
+    /// A player attempted a move the rules do not allow.
+    IllegalMove {
+        position: Position,
+        reason: String,
+    },
+    /// A player crashed or disconnected.
+    Crash(String),
+    /// A player resigned.
+    Resignation,
+    /// The coordinator decided the result without the game reaching its
+    /// end, as when the maximum number of moves is reached.
+    Adjudication,
+    /// The game ended in a draw on a full board.
+    Draw,
+}
+
+impl fmt::Display for Termination
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match &self {
+            Termination::Five(line) => write!(f, "five in a row ({})", line),
+            Termination::TurnTimeout => write!(f, "turn timeout"),
+            Termination::MatchTimeout => write!(f, "match timeout"),
+            Termination::PeriodTimeout(period) => write!(f, "timeout in byo-yomi period {}", period),
+            Termination::IllegalMove { position, reason } => {
+                write!(f, "illegal move at {} ({})", position, reason)
+            },
+            Termination::Crash(reason) => write!(f, "crash ({})", reason),
+            Termination::Resignation => write!(f, "resignation"),
+            Termination::Adjudication => write!(f, "adjudication"),
+            Termination::Draw => write!(f, "draw"),
+        }
+    }
+}
+
+/// One of the two engines coordinated, named after the order their
+/// interfaces were given in, the first one playing black in the first game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Engine
+{
+    /// The engine given as the black player interface.
+    #[default]
+    First,
+    /// The engine given as the white player interface.
+    Second,
+}
+
+impl Engine
+{
+    /// Returns the other engine.
+    pub fn other(
+        &self,
+    ) -> Self
+    {
+        match self {
+            Engine::First => Engine::Second,
+            Engine::Second => Engine::First,
+        }
+    }
+}
+
+impl fmt::Display for Engine
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match &self {
+            Engine::First => write!(f, "first engine"),
+            Engine::Second => write!(f, "second engine"),
+        }
+    }
+}
+
+/// The result of a game: who won, why, and who is to blame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameResult
+{
+    /// The winner of the game, `None` on a draw.
+    pub winner: Option<PlayerColor>,
+    /// The way the game ended.
+    pub termination: Termination,
+    /// The player whose fault ended the game, if any.
+    pub offender: Option<PlayerColor>,
+    /// The engine that played black.
+    pub black_engine: Engine,
+}
+
+impl GameResult
+{
+    /// Creates the result of a game lost by a player's fault.
+    pub fn forfeit(
+        offender: PlayerColor,
+        termination: Termination,
+    ) -> Self
+    {
+        Self {
+            winner: Some(offender.other()),
+            termination,
+            offender: Some(offender),
+            black_engine: Engine::default(),
+        }
+    }
+
+    /// Returns the engine that played a color.
+    pub fn engine(
+        &self,
+        color: PlayerColor,
+    ) -> Engine
+    {
+        match color {
+            PlayerColor::Black => self.black_engine,
+            PlayerColor::White => self.black_engine.other(),
+        }
+    }
+
+    /// Returns the engine that won the game, `None` on a draw.
+    pub fn winner_engine(
+        &self,
+    ) -> Option<Engine>
+    {
+        self.winner.map(|winner| self.engine(winner))
+    }
+}
+
+impl From<GameEnd> for GameResult
+{
+    fn from(end: GameEnd) -> Self
+    {
+        match end {
+            GameEnd::Win(winner, WinReason::Line(line)) => Self {
+                winner: Some(winner),
+                termination: Termination::Five(line),
+                offender: None,
+                black_engine: Engine::default(),
+            },
+            GameEnd::Win(winner, WinReason::Timeout(TimeoutKind::Turn)) => {
+                Self::forfeit(winner.other(), Termination::TurnTimeout)
+            },
+            GameEnd::Win(winner, WinReason::Timeout(TimeoutKind::Match)) => {
+                Self::forfeit(winner.other(), Termination::MatchTimeout)
+            },
+            GameEnd::Win(winner, WinReason::Timeout(TimeoutKind::Period(period))) => {
+                Self::forfeit(winner.other(), Termination::PeriodTimeout(period))
+            },
+            GameEnd::Win(winner, WinReason::ForbiddenMove(forbidden_move, position)) => {
+                Self::forfeit(winner.other(), Termination::IllegalMove {
+                    position,
+                    reason: format!("forbidden {}", forbidden_move),
+                })
+            },
+            GameEnd::Draw(DrawReason::FullBoard) => Self {
+                winner: None,
+                termination: Termination::Draw,
+                offender: None,
+                black_engine: Engine::default(),
+            },
+            // The game was cut short rather than played to its end.
+            GameEnd::Draw(DrawReason::MoveLimit) => Self {
+                winner: None,
+                termination: Termination::Adjudication,
+                offender: None,
+                black_engine: Engine::default(),
+            },
+        }
+    }
+}
+
+/// Turns an error caused by a player into the loss of the game by this
+/// player. Other errors are given back.
+impl TryFrom<Error> for GameResult
+{
+    type Error = Error;
+
+    fn try_from(error: Error) -> Result<Self, Self::Error>
+    {
+        match error {
+            Error::IllegalMove { color, position, error } => {
+                Ok(Self::forfeit(color, Termination::IllegalMove {
+                    position,
+                    reason: error.to_string(),
+                }))
+            },
+            Error::NotifyError { error, color } => {
+                Ok(Self::forfeit(color, Termination::Crash(error.to_string())))
+            },
+            Error::ListenError { error, color } => {
+                Ok(Self::forfeit(color, Termination::Crash(error.to_string())))
+            },
+            error => Err(error),
+        }
+    }
+}
+
+impl fmt::Display for GameResult
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match self.winner {
+            Some(winner) => write!(f, "{} ({}) won by {}", winner, self.engine(winner), self.termination)?,
+            None => write!(f, "{}", self.termination)?,
+        }
+
+        match self.offender {
+            Some(offender) => write!(f, " of {} ({})", offender, self.engine(offender)),
+            None => Ok(()),
+        }
+    }
+}
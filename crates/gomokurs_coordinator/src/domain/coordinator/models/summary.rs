@@ -0,0 +1,181 @@
+//! Models of the scores of the engines over several games.
+
+use std::fmt;
+use crate::domain::coordinator::models::actions::PlayerColor;
+use crate::domain::coordinator::models::result::{Engine, GameResult};
+
+/// The wins, losses and draws of an engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Score
+{
+    pub wins: u32,
+    pub losses: u32,
+    pub draws: u32,
+}
+
+impl Score
+{
+    /// Returns the number of games scored.
+    pub fn games(
+        &self,
+    ) -> u32
+    {
+        self.wins + self.losses + self.draws
+    }
+
+    /// Returns the points scored, one per win and a half per draw.
+    pub fn points(
+        &self,
+    ) -> f64
+    {
+        self.wins as f64 + self.draws as f64 / 2.0
+    }
+}
+
+impl std::ops::Add for Score
+{
+    type Output = Score;
+
+    fn add(
+        self,
+        other: Score,
+    ) -> Score
+    {
+        Score {
+            wins: self.wins + other.wins,
+            losses: self.losses + other.losses,
+            draws: self.draws + other.draws,
+        }
+    }
+}
+
+impl fmt::Display for Score
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        write!(f, "+{} -{} ={}", self.wins, self.losses, self.draws)
+    }
+}
+
+/// The score of an engine with each color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EngineScore
+{
+    pub as_black: Score,
+    pub as_white: Score,
+}
+
+impl EngineScore
+{
+    /// Returns the score of the engine with both colors.
+    pub fn total(
+        &self,
+    ) -> Score
+    {
+        self.as_black + self.as_white
+    }
+
+    /// Returns the score of the engine with a color.
+    fn color_mut(
+        &mut self,
+        color: PlayerColor,
+    ) -> &mut Score
+    {
+        match color {
+            PlayerColor::Black => &mut self.as_black,
+            PlayerColor::White => &mut self.as_white,
+        }
+    }
+}
+
+impl fmt::Display for EngineScore
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        write!(f, "{} (black {}, white {})", self.total(), self.as_black, self.as_white)
+    }
+}
+
+/// The results of the games played between two engines, and their scores.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MatchSummary
+{
+    /// The results of the games, in the order they were played.
+    pub games: Vec<GameResult>,
+    /// The score of the first engine.
+    pub first: EngineScore,
+    /// The score of the second engine.
+    pub second: EngineScore,
+    /// The reason the coordination stopped before the match was over, if
+    /// it did.
+    pub aborted: Option<String>,
+}
+
+impl MatchSummary
+{
+    /// Adds the result of a game to the summary.
+    pub fn record(
+        &mut self,
+        result: GameResult,
+    )
+    {
+        for color in [PlayerColor::Black, PlayerColor::White] {
+            let engine = result.engine(color);
+            let score = match engine {
+                Engine::First => self.first.color_mut(color),
+                Engine::Second => self.second.color_mut(color),
+            };
+
+            match result.winner {
+                Some(winner) if winner == color => score.wins += 1,
+                Some(_) => score.losses += 1,
+                None => score.draws += 1,
+            }
+        }
+
+        self.games.push(result);
+    }
+
+    /// Returns the score of an engine.
+    pub fn score(
+        &self,
+        engine: Engine,
+    ) -> &EngineScore
+    {
+        match engine {
+            Engine::First => &self.first,
+            Engine::Second => &self.second,
+        }
+    }
+}
+
+impl fmt::Display for MatchSummary
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        write!(
+            f,
+            "{} games, {}: {}, {}: {}",
+            self.games.len(),
+            Engine::First,
+            self.first,
+            Engine::Second,
+            self.second,
+        )?;
+
+        if let Some(reason) = &self.aborted {
+            write!(f, ", aborted: {}", reason)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,12 @@
+//! Models of the Swap2 opening phase.
+
+/// The decision awaited during a Swap2 opening.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Swap2Phase {
+    /// The first player has to place the first three stones.
+    Placement,
+    /// The second player has to choose a color or place two more stones.
+    SecondPlayerChoice,
+    /// The first player has to choose a color after five stones.
+    FirstPlayerChoice,
+}
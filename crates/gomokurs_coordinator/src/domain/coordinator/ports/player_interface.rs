@@ -8,7 +8,7 @@ pub trait PlayerInterface: Send + Sync + 'static {
     ///
     /// * `color` - The `PlayerColor` representing the player being listened to.
     /// * `tx` - A `Sender` channel for forwarding `(PlayerColor, PlayerAction)`
-    /// tuples.
+    ///   tuples.
     fn listen(
         &self,
         color: PlayerColor,
@@ -19,10 +19,10 @@ pub trait PlayerInterface: Send + Sync + 'static {
     /// 
     /// # Arguments
     /// 
-    /// * `size` - The size of the gomoku board, both width and height.
+    /// * `size` - The size of the gomoku board, width and height may differ.
     fn notify_start(
         &self,
-        size: u8,
+        size: BoardSize,
     ) -> impl std::future::Future<Output = Result<(), NotifyError>>;
 
     /// Notifies the player of the to initialize its board with the same
@@ -41,6 +41,16 @@ pub trait PlayerInterface: Send + Sync + 'static {
         position: Position,
     ) -> impl std::future::Future<Output = Result<(), NotifyError>>;
     
+    /// Notifies the player that a move was taken back.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `position` - The position of the stone to remove from the board.
+    fn notify_takeback(
+        &self,
+        position: Position,
+    ) -> impl std::future::Future<Output = Result<(), NotifyError>>;
+
     /// Notifies the player that it is their turn to make the first move.
     fn notify_begin(
         &self,
@@ -57,6 +67,17 @@ pub trait PlayerInterface: Send + Sync + 'static {
         turns: Vec<RelativeTurn>,
     ) -> impl std::future::Future<Output = Result<(), NotifyError>>;
 
+    /// Asks the player for its decision during a Swap2 opening.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `stones` - The stones already placed, in the order they were
+    ///   played, starting with black.
+    fn notify_swap2_board(
+        &self,
+        stones: Vec<Position>,
+    ) -> impl std::future::Future<Output = Result<(), NotifyError>>;
+
     /// Sends general game-related information to the player.
     /// 
     /// # Arguments
@@ -4,7 +4,7 @@
 //! listening to the actions of both the black and white players and forwarding
 //! them to the GameManagerService for handling.
 
-use crate::domain::coordinator::models::{GameEnd, Error};
+use crate::domain::coordinator::models::{MatchSummary, Error};
 use crate::domain::coordinator::ports::PlayerInterface;
 pub use gomokurs_game_engine::domain::game_engine::ports::GameEngineService;
 
@@ -21,7 +21,15 @@ where
     G: GameEngineService,
     I: PlayerInterface,
 {
+    /// Coordinates games between the players until the number of games of
+    /// the mode is played, or until one of them can no longer play.
+    ///
+    /// # Returns
+    /// The summary of the games played, along with the reason the
+    /// coordination stopped early if a player could no longer play. Errors
+    /// are returned only when the coordination fails for a reason no player
+    /// can be blamed for.
     fn run(
         &mut self,
-    ) -> impl std::future::Future<Output = Result<GameEnd, Error>>;
+    ) -> impl std::future::Future<Output = Result<MatchSummary, Error>>;
 }
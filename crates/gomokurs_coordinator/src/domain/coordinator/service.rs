@@ -1,5 +1,8 @@
+#[allow(clippy::module_inception)]
 pub mod service;
 mod player;
 mod handlers;
+mod swap2;
+mod handshake;
 
 pub use service::*;
@@ -18,7 +18,9 @@ where
             PlayerColor::White => &mut self.white,
         };
 
-        if player.ready {
+        if player.awaiting_takeback {
+            player.awaiting_takeback = false;
+        } else if player.ready {
             player.interface
                 .notify_error("player has already declared to be ready")
                 .await
@@ -35,66 +37,59 @@ where
         color: PlayerColor,
         position: Position,
     ) -> Result<Option<GameEnd>, Error>
-    {         
-        let (player, opponent_player) = match color {
-            PlayerColor::Black => (&self.black, &self.white),
-            PlayerColor::White => (&self.white, &self.black),
-        };
+    {
+        if !self.player(color).ready {
+            let player = self.player(color);
 
-        if !player.ready {
             player.interface
-                .notify_error("player has already declared to be ready")
+                .notify_error("player has not declared to be ready")
                 .await
                 .map_err(|error| Error::NotifyError { error, color: player.color })?;
+        } else if self.player(color).awaiting_takeback {
+            // The move was thought over before the takeback, and is stale.
+            tracing::debug!("ignored {} move at {} sent before a takeback", color, position);
+        } else if self.swap2.is_some() {
+            self.handle_swap2_play(color, position).await?;
         } else {
-            match self.game.register_player_move(player.color, position).await {
+            match self.game.register_player_move(color, position).await {
                 Ok(res) => {
                     if let Some(end) = res {
-                        match end {
-                            GameEnd::Draw => {
-                                player.interface.notify_result(RelativeGameEnd::Draw)
-                                    .await
-                                    .map_err(|error| Error::NotifyError { error, color: player.color })?;
-
-                                opponent_player.interface.notify_result(RelativeGameEnd::Draw)
-                                    .await
-                                    .map_err(|error| Error::NotifyError { error, color: opponent_player.color })?;
-                            },
-                            GameEnd::Win(winner) => {
-                                if winner == player.color {
-                                    player.interface.notify_result(RelativeGameEnd::Win)
-                                        .await
-                                        .map_err(|error| Error::NotifyError { error, color: player.color })?;
-
-                                    opponent_player.interface.notify_result(RelativeGameEnd::Loose)
-                                        .await
-                                        .map_err(|error| Error::NotifyError { error, color: opponent_player.color })?;
-                                } else {
-                                    player.interface.notify_result(RelativeGameEnd::Loose)
-                                        .await
-                                        .map_err(|error| Error::NotifyError { error, color: player.color })?;
-
-                                    opponent_player.interface.notify_result(RelativeGameEnd::Win)
-                                        .await
-                                        .map_err(|error| Error::NotifyError { error, color: opponent_player.color })?;
-                                }
-                            }
-                        }
-
                         return Ok(Some(end));
                     } else {
-                        opponent_player.interface.notify_turn(position)
-                            .await
-                            .map_err(|error| Error::NotifyError { error, color: opponent_player.color })?;
+                        let opponent_player = self.player(color.other());
+
+                        if opponent_player.board_synced {
+                            self.notify_time_left(color.other()).await?;
+                            opponent_player.interface.notify_turn(position)
+                                .await
+                                .map_err(|error| Error::NotifyError { error, color: opponent_player.color })?;
+                        } else {
+                            self.sync_board(color.other()).await?;
+                        }
                     }
                 },
                 Err(e) => {
+                    let player = self.player(color);
+
                     player.interface
                         .notify_error(&e.to_string()) // There is surely a proper way to handle this
                         .await
                         .map_err(|error| Error::NotifyError { error, color: player.color })?;
 
-                    return Err(e.into());
+                    // A rejected forbidden move leaves black to play another
+                    // one, the error being its request to do so.
+                    if let GameEngineError::ForbiddenMove(forbidden_move) = e {
+                        match self.game.get_rules().await.forbidden_move_policy {
+                            ForbiddenMovePolicy::Reject => return Ok(None),
+                            ForbiddenMovePolicy::Loss => {
+                                let reason = WinReason::ForbiddenMove(forbidden_move, position);
+
+                                return Ok(Some(GameEnd::Win(color.other(), reason)));
+                            },
+                        }
+                    }
+
+                    return Err(Error::IllegalMove { color, position, error: e });
                 },
             }
         }
@@ -102,6 +97,27 @@ where
         Ok(None)
     }
 
+    /// Notifies both players of the result of the game.
+    pub(crate) async fn notify_game_result(
+        &self,
+        game_result: &GameResult,
+    ) -> Result<(), Error>
+    {
+        for player in [&self.black, &self.white] {
+            let result = match game_result.winner {
+                None => RelativeGameEnd::Draw,
+                Some(winner) if winner == player.color => RelativeGameEnd::Win,
+                Some(_) => RelativeGameEnd::Loose,
+            };
+
+            player.interface.notify_result(result)
+                .await
+                .map_err(|error| Error::NotifyError { error, color: player.color })?;
+        }
+
+        Ok(())
+    }
+
     pub async fn handle_metadata(
         &mut self,
         color: PlayerColor,
@@ -119,26 +135,52 @@ where
     }
 
     pub async fn handle_unknown(
-        &self,
+        &mut self,
         color: PlayerColor,
         content: String,
     )-> Result<(), Error>
     {
         tracing::error!("{} send unknown error: \"{}\"", color, content);
 
+        self.reject_takeback(color);
+
         Ok(())
     }
     
     pub async fn handle_error(
-        &self,
+        &mut self,
         color: PlayerColor,
         content: String,
     ) -> Result<(), Error> {    
         tracing::error!("{} send error: \"{}\"", color, content);
 
+        self.reject_takeback(color);
+
         Ok(())
     }
 
+    /// Marks a player that refused a takeback as needing the whole board
+    /// before its next move. Returns whether a takeback was pending.
+    fn reject_takeback(
+        &mut self,
+        color: PlayerColor,
+    ) -> bool
+    {
+        let player = match color {
+            PlayerColor::Black => &mut self.black,
+            PlayerColor::White => &mut self.white,
+        };
+
+        if player.awaiting_takeback {
+            player.awaiting_takeback = false;
+            player.board_synced = false;
+
+            true
+        } else {
+            false
+        }
+    }
+
     pub async fn handle_message(
         &self,
         color: PlayerColor,
@@ -0,0 +1,103 @@
+//! Handshake opening every game.
+//!
+//! Once told to start or restart, each player answers when it is ready to
+//! play. The first move is only asked for, and the clocks only start, once
+//! both players are ready. A player refusing to start, crashing, or not
+//! ready before the startup timeout, stops the coordination without losing
+//! a game that never began.
+
+use crate::domain::coordinator::ports::PlayerInterface;
+use gomokurs_game_engine::domain::game_engine::ports::GameEngineService;
+use crate::domain::coordinator::service::service::{Listeners, Service};
+use crate::domain::coordinator::models::*;
+use tokio::sync::mpsc::Receiver;
+use tokio::time::{sleep_until, Instant};
+
+impl<G, I> Service<G, I>
+where
+    G: GameEngineService,
+    I: PlayerInterface
+{
+    /// Waits for both players to be ready, then asks for the first move.
+    pub async fn open_game(
+        &mut self,
+        actions_rx: &mut Receiver<(PlayerColor, PlayerAction)>,
+        listeners: &mut Listeners,
+    ) -> Result<(), Error>
+    {
+        self.handshake(actions_rx, listeners)
+            .await
+            .map_err(Error::into_startup_failure)?;
+
+        self.begin_game().await
+    }
+
+    /// Waits for both players to declare to be ready, within the startup
+    /// timeout.
+    async fn handshake(
+        &mut self,
+        actions_rx: &mut Receiver<(PlayerColor, PlayerAction)>,
+        listeners: &mut Listeners,
+    ) -> Result<(), Error>
+    {
+        tracing::debug!("waiting for the players to be ready");
+
+        let deadline = Instant::now() + self.startup_timeout;
+
+        while !(self.black.ready && self.white.ready) {
+            tokio::select! {
+                Some((color, action)) = actions_rx.recv() => {
+                    let color = self.seat_color(color);
+
+                    tracing::debug!("received {:?} from {} during handshake", action, color);
+
+                    self.handle_handshake_action(color, action).await?;
+                },
+                Some(res) = listeners.join_next() => {
+                    return Err(self.listener_error(res));
+                },
+                _ = sleep_until(deadline) => {
+                    let color = if self.black.ready { PlayerColor::White } else { PlayerColor::Black };
+
+                    return Err(Error::StartupTimeout { color, timeout: self.startup_timeout });
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Handles an action of a player before the game began.
+    async fn handle_handshake_action(
+        &mut self,
+        color: PlayerColor,
+        action: PlayerAction,
+    ) -> Result<(), Error>
+    {
+        match action {
+            PlayerAction::Ready => self.handle_ready(color).await,
+            PlayerAction::Error(content) => {
+                tracing::error!("{} send error: \"{}\"", color, content);
+
+                let size = self.game.get_board_size().await;
+
+                Err(Error::StartRejected { color, size, content })
+            },
+            PlayerAction::Metadata(metadata) => self.handle_metadata(color, metadata).await,
+            PlayerAction::Unknown(content) => self.handle_unknown(color, content).await,
+            PlayerAction::Message(content) => self.handle_message(color, content).await,
+            PlayerAction::Debug(content) => self.handle_debug(color, content).await,
+            PlayerAction::Play(_)
+            | PlayerAction::Suggestion(_)
+            | PlayerAction::Swap
+            | PlayerAction::PlaceStones(_) => {
+                let player = self.player(color);
+
+                player.interface
+                    .notify_error("the game has not begun")
+                    .await
+                    .map_err(|error| Error::NotifyError { error, color: player.color })
+            },
+        }
+    }
+}
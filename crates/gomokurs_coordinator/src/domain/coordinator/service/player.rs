@@ -1,5 +1,6 @@
 use crate::domain::coordinator::ports::PlayerInterface;
 use crate::domain::coordinator::models::{PlayerColor, PlayerMetadata};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[derive(Debug, Clone)]
@@ -11,8 +12,17 @@ where
     pub color: PlayerColor,
     /// Indicates if the player has declared readiness to play.
     pub ready: bool,
+    /// Indicates if the player knows every stone currently on the board.
+    pub board_synced: bool,
+    /// Indicates if the player has yet to acknowledge a takeback.
+    pub awaiting_takeback: bool,
+    /// The number of illegal moves the player attempted during the current
+    /// game.
+    pub illegal_moves: u32,
     /// Metadata about the player as key-value pairs.
     pub metadata: Option<PlayerMetadata>,
+    /// The folder the player program may keep persistent files in, if any.
+    pub folder: Option<PathBuf>,
     /// The notifier used to communicate with the player program.
     pub interface: Arc<I>,
 }
@@ -24,12 +34,17 @@ where
     pub fn new(
         color: PlayerColor,
         interface: Arc<I>,
+        folder: Option<PathBuf>,
     ) -> Self {
         Self {
             color,
             ready: false,
+            board_synced: true,
+            awaiting_takeback: false,
+            illegal_moves: 0,
             metadata: None,
-            interface: interface,
+            folder,
+            interface,
         }
     }
 }
\ No newline at end of file
@@ -5,11 +5,17 @@
 
 use crate::domain::coordinator::ports::{GameEngineService, CoordinatorService, PlayerInterface};
 use crate::domain::coordinator::service::player::Player;
-use tokio::task::JoinSet;
-use tokio::sync::mpsc::channel;
+use tokio::task::{JoinError, JoinSet};
+use tokio::sync::mpsc::{channel, Receiver, Sender};
+use tokio::time::Duration;
 use crate::domain::coordinator::models::*;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+/// The listeners of both players, each giving back the color of its
+/// interface when it stops.
+pub type Listeners = JoinSet<(PlayerColor, Result<(), ListenError>)>;
+
 #[derive(Debug, Clone)]
 pub struct CreateCoordinatorConfiguration<G, I>
 where
@@ -20,9 +26,25 @@ where
     pub black_player_interface: Arc<I>,
     pub white_player_interface: Arc<I>,
     pub game_mode: Mode,
+    pub opening: Opening,
+    pub illegal_move_policy: IllegalMovePolicy,
+    /// The kind of game announced to the players.
+    pub game_type: GameType,
+    /// The memory limit announced to the players in bytes, zero meaning no
+    /// limit.
+    pub max_memory: u64,
+    /// The folders the black and white player programs may keep persistent
+    /// files in, if any.
+    pub black_player_folder: Option<PathBuf>,
+    pub white_player_folder: Option<PathBuf>,
+    /// The time the players have to get ready once told to start a game.
+    pub startup_timeout: Duration,
+    /// Indicates if the engines exchange colors after each game in loop
+    /// mode.
+    pub alternate_colors: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct Service<G, I>
 where
     G: GameEngineService,
@@ -32,6 +54,24 @@ where
     pub black: Player<I>,
     pub white: Player<I>,
     pub mode: Mode,
+    pub opening: Opening,
+    pub illegal_move_policy: IllegalMovePolicy,
+    pub game_type: GameType,
+    pub max_memory: u64,
+    pub startup_timeout: Duration,
+    /// The pending decision of the Swap2 opening, if it is being played.
+    pub swap2: Option<Swap2Phase>,
+    /// Indicates if the players have swapped the colors given by their
+    /// interfaces.
+    pub swapped: bool,
+    pub alternate_colors: bool,
+    /// Indicates if the players had swapped colors when the current game
+    /// started, before the opening.
+    pub game_swapped: bool,
+    /// The results of the games played so far.
+    pub summary: MatchSummary,
+    /// The commands given to the coordinator, once a sender was requested.
+    pub commands: Option<Receiver<Command>>,
 }
 
 impl<G, I> Service<G, I>
@@ -45,31 +85,232 @@ where
     ) -> Self {
         Self {
             game: cfg.game_engine,
-            black: Player::new(PlayerColor::Black, cfg.black_player_interface),
-            white: Player::new(PlayerColor::White, cfg.white_player_interface),
+            black: Player::new(PlayerColor::Black, cfg.black_player_interface, cfg.black_player_folder),
+            white: Player::new(PlayerColor::White, cfg.white_player_interface, cfg.white_player_folder),
             mode: cfg.game_mode,
+            opening: cfg.opening,
+            illegal_move_policy: cfg.illegal_move_policy,
+            game_type: cfg.game_type,
+            max_memory: cfg.max_memory,
+            startup_timeout: cfg.startup_timeout,
+            swap2: None,
+            swapped: false,
+            alternate_colors: cfg.alternate_colors,
+            game_swapped: false,
+            summary: MatchSummary::default(),
+            commands: None,
+        }
+    }
+
+    /// Returns a sender of commands to the coordinator, replacing the
+    /// channel of any previous sender.
+    pub fn command_sender(
+        &mut self,
+    ) -> Sender<Command>
+    {
+        let (commands_tx, commands_rx) = channel::<Command>(100);
+
+        self.commands = Some(commands_rx);
+
+        commands_tx
+    }
+
+    /// Returns the player currently playing the given color.
+    pub fn player(
+        &self,
+        color: PlayerColor,
+    ) -> &Player<I>
+    {
+        match color {
+            PlayerColor::Black => &self.black,
+            PlayerColor::White => &self.white,
+        }
+    }
+
+    /// Returns the color currently played by the player whose interface was
+    /// created for the given color.
+    pub fn seat_color(
+        &self,
+        color: PlayerColor,
+    ) -> PlayerColor
+    {
+        if self.swapped { color.other() } else { color }
+    }
+
+    /// Returns the engine currently playing a color.
+    pub fn engine(
+        &self,
+        color: PlayerColor,
+    ) -> Engine
+    {
+        match self.seat_color(color) {
+            PlayerColor::Black => Engine::First,
+            PlayerColor::White => Engine::Second,
         }
     }
 
+    /// Credits the result of the current game to the engines that played
+    /// it.
+    fn credit(
+        &self,
+        result: GameResult,
+    ) -> GameResult
+    {
+        GameResult {
+            black_engine: self.engine(PlayerColor::Black),
+            ..result
+        }
+    }
+
+    /// Turns the end of a player listener into the error it stopped with.
+    pub fn listener_error(
+        &self,
+        res: Result<(PlayerColor, Result<(), ListenError>), JoinError>,
+    ) -> Error
+    {
+        match res {
+            Err(e) => e.into(),
+            Ok((color, listener_res)) => {
+                let color = self.seat_color(color);
+                let error = listener_res.err().unwrap_or(ListenError::Disconnected);
+
+                Error::ListenError { error, color }
+            },
+        }
+    }
+
+    /// Exchanges the colors of the players.
+    pub fn swap_colors(
+        &mut self,
+    )
+    {
+        std::mem::swap(&mut self.black, &mut self.white);
+        self.black.color = PlayerColor::Black;
+        self.white.color = PlayerColor::White;
+        self.swapped = !self.swapped;
+    }
+
+    /// Sends the settings of the game to a player, after it started.
+    async fn notify_preamble(
+        &self,
+        color: PlayerColor,
+    ) -> Result<(), Error>
+    {
+        let player = self.player(color);
+        let mut infos = vec![
+            Information::TimeoutTurn(self.game.get_turn_duration().await.as_millis() as u64),
+            Information::TimeoutMatch(self.game.get_match_duration().await.as_millis() as u64),
+            Information::MaxMemory(self.max_memory),
+            Information::TimeLeft(self.game.get_time_left(color).await.total().as_millis() as u64),
+            Information::GameType(self.game_type.as_gomocup_game_type()),
+            Information::Rule(self.game.get_rules().await.as_gomocup_rule()),
+        ];
+        infos.extend(player.folder.clone().map(Information::Folder));
+
+        for info in infos {
+            player.interface
+                .notify_info(info)
+                .await
+                .map_err(|error| Error::NotifyError { error, color: player.color })?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the time left to a player, before its turn. With byo-yomi, the
+    /// time left includes the periods left, and the turn timeout is the time
+    /// the turn may last without using up a period.
+    pub async fn notify_time_left(
+        &self,
+        color: PlayerColor,
+    ) -> Result<(), Error>
+    {
+        let time_left = self.game.get_time_left(color).await;
+        let player = self.player(color);
+
+        if !time_left.period.is_zero() {
+            player.interface
+                .notify_info(Information::TimeoutTurn(time_left.turn.as_millis() as u64))
+                .await
+                .map_err(|error| Error::NotifyError { error, color: player.color })?;
+        }
+
+        player.interface
+            .notify_info(Information::TimeLeft(time_left.total().as_millis() as u64))
+            .await
+            .map_err(|error| Error::NotifyError { error, color: player.color })
+    }
+
+    /// Sends every stone of the current game to a player.
+    pub async fn sync_board(
+        &mut self,
+        color: PlayerColor,
+    ) -> Result<(), Error>
+    {
+        let turns = self.game.get_moves().await
+            .iter()
+            .map(|recorded_move| RelativeTurn {
+                position: recorded_move.position,
+                field: if recorded_move.color == color {
+                    RelativeField::OwnStone
+                } else {
+                    RelativeField::OpponentStone
+                },
+            })
+            .collect();
+
+        self.notify_time_left(color).await?;
+
+        let player = match color {
+            PlayerColor::Black => &mut self.black,
+            PlayerColor::White => &mut self.white,
+        };
+
+        player.interface
+            .notify_board(turns)
+            .await
+            .map_err(|error| Error::NotifyError { error, color })?;
+        player.board_synced = true;
+
+        Ok(())
+    }
+
+    /// Asks for the first move of the game, according to the opening.
+    pub async fn begin_game(
+        &mut self
+    ) -> Result<(), Error>
+    {
+        match self.opening {
+            Opening::Standard => {
+                self.notify_time_left(PlayerColor::Black).await?;
+                self.black.interface
+                    .notify_begin()
+                    .await
+                    .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
+            },
+            Opening::Swap2 => self.start_swap2().await?,
+        }
+
+        Ok(())
+    }
+
     pub async fn start_game(
-        &self
+        &mut self
     ) -> Result<(), Error>
     {
         let size = self.game.get_board_size().await;
 
         self.black.interface
-            .notify_start(size.x)
+            .notify_start(size)
             .await
             .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
         self.white.interface
-            .notify_start(size.x)
+            .notify_start(size)
             .await
             .map_err(|error| Error::NotifyError { error, color: self.white.color })?;
 
-        self.black.interface
-            .notify_begin()
-            .await
-            .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
+        self.notify_preamble(PlayerColor::Black).await?;
+        self.notify_preamble(PlayerColor::White).await?;
 
         Ok(())
     }
@@ -89,15 +330,210 @@ where
         Ok(())
     }
 
+    /// Takes back the last move of the current game and asks its player to
+    /// play again.
+    ///
+    /// The opponent is sent **TAKEBACK** to remove the stone, and is
+    /// expected to answer **READY**; answering **UNKNOWN** or **ERROR** gets
+    /// it the whole board before its next move instead. The player of the
+    /// undone move is sent the whole board with **BOARD**, and answers it
+    /// with its move again.
+    pub async fn takeback(
+        &mut self
+    ) -> Result<(), Error>
+    {
+        if self.swap2.is_some() {
+            tracing::warn!("cannot take back a move during the swap2 opening");
+
+            return Ok(());
+        } else if self.game.get_moves().await.is_empty() {
+            tracing::warn!("no move to take back");
+
+            return Ok(());
+        }
+
+        let (color, position) = self.game.undo().await?;
+
+        tracing::info!("took back {} move at {}", color, position);
+
+        let opponent = match color.other() {
+            PlayerColor::Black => &mut self.black,
+            PlayerColor::White => &mut self.white,
+        };
+
+        opponent.interface
+            .notify_takeback(position)
+            .await
+            .map_err(|error| Error::NotifyError { error, color: opponent.color })?;
+        opponent.awaiting_takeback = true;
+
+        self.sync_board(color).await
+    }
+
+    /// Handles a command given to the coordinator during a game.
+    ///
+    /// # Returns
+    /// The result of the game if the command ended it.
+    async fn handle_command(
+        &mut self,
+        command: Command,
+    ) -> Result<Option<GameResult>, Error>
+    {
+        match command {
+            Command::Takeback => self.takeback().await?,
+            Command::Resign(color) => {
+                tracing::info!("{} resigned", color);
+
+                return Ok(Some(GameResult::forfeit(color, Termination::Resignation)));
+            },
+        }
+
+        Ok(None)
+    }
+
+    /// Handles an action of a player.
+    ///
+    /// # Returns
+    /// The result of the game if the action ended it.
+    async fn handle_action(
+        &mut self,
+        color: PlayerColor,
+        action: PlayerAction,
+    ) -> Result<Option<GameResult>, Error>
+    {
+        match action {
+            PlayerAction::Ready => self.handle_ready(color).await?,
+            PlayerAction::Play(position) => {
+                match self.handle_play(color, position).await {
+                    Ok(end) => return Ok(end.map(GameResult::from)),
+                    Err(Error::IllegalMove { color, .. }) if self.retry_illegal_move(color) => {},
+                    Err(error) => return Err(error),
+                }
+            },
+            PlayerAction::Metadata(metadata) => self.handle_metadata(color, metadata).await?,
+            PlayerAction::Unknown(content) => self.handle_unknown(color, content).await?,
+            PlayerAction::Error(content) => self.handle_error(color, content).await?,
+            PlayerAction::Message(content) => self.handle_message(color, content).await?,
+            PlayerAction::Debug(content) => self.handle_debug(color, content).await?,
+            PlayerAction::Suggestion(position) => self.handle_suggestion(color, position).await?,
+            PlayerAction::Swap => self.handle_swap(color).await?,
+            PlayerAction::PlaceStones(stones) => {
+                match self.handle_place_stones(color, stones).await {
+                    Ok(()) => {},
+                    Err(Error::IllegalMove { color, .. }) if self.retry_illegal_move(color) => {},
+                    Err(error) => return Err(error),
+                }
+            },
+        }
+
+        Ok(None)
+    }
+
+    /// Counts an illegal move of a player against the illegal move policy.
+    /// Returns whether the player may play again.
+    fn retry_illegal_move(
+        &mut self,
+        color: PlayerColor,
+    ) -> bool
+    {
+        let player = match color {
+            PlayerColor::Black => &mut self.black,
+            PlayerColor::White => &mut self.white,
+        };
+
+        match self.illegal_move_policy {
+            IllegalMovePolicy::Retry(retries) if player.illegal_moves < retries => {
+                player.illegal_moves += 1;
+
+                true
+            },
+            _ => false,
+        }
+    }
+
+    /// Records the result of a game and notifies the players of it, then
+    /// either ends the coordination or starts the next game depending on
+    /// the mode.
+    ///
+    /// # Returns
+    /// Whether the coordination is over.
+    async fn finish_game(
+        &mut self,
+        result: GameResult,
+    ) -> Result<bool, Error>
+    {
+        tracing::info!("game over: {}", result);
+
+        let crashed = matches!(result.termination, Termination::Crash(_));
+
+        self.summary.record(result.clone());
+
+        // A crashed player can neither be notified nor play another game.
+        if crashed {
+            return Ok(true);
+        }
+
+        self.notify_game_result(&result).await?;
+
+        let over = match self.mode {
+            Mode::SingleGame => true,
+            Mode::Loop => false,
+            Mode::Match(games) => self.summary.games.len() >= games as usize,
+        };
+
+        if over {
+            self.end_game().await?;
+        } else {
+            self.restart_game().await.map_err(Error::into_startup_failure)?;
+        }
+
+        Ok(over)
+    }
+
+    /// Stops the coordination on an error.
+    ///
+    /// # Returns
+    /// The summary of the games played so far along with the reason, unless
+    /// no player is to blame for the error.
+    fn abort(
+        &mut self,
+        error: Error,
+    ) -> Result<MatchSummary, Error>
+    {
+        match error {
+            Error::ChannelClosed | Error::JoinError(_) | Error::GameError(_) | Error::Unknown(_) => Err(error),
+            error => {
+                tracing::warn!("coordination stopped: {}", error);
+
+                self.summary.aborted = Some(error.to_string());
+
+                Ok(std::mem::take(&mut self.summary))
+            },
+        }
+    }
+
     pub async fn restart_game(
         &mut self
     ) -> Result<(), Error>
     {
-        tracing::debug!("loop mode - restart game");
+        tracing::debug!("{} mode - restart game", self.mode);
 
         self.game.reset().await?;
-        self.black.ready = false;
-        self.white.ready = false;
+        self.swap2 = None;
+
+        // When alternating, the engine that played white when the previous
+        // game started plays black, whatever colors the opening gave.
+        if self.alternate_colors && self.swapped == self.game_swapped {
+            self.swap_colors();
+        }
+        self.game_swapped = self.swapped;
+
+        for player in [&mut self.black, &mut self.white] {
+            player.ready = false;
+            player.board_synced = true;
+            player.awaiting_takeback = false;
+            player.illegal_moves = 0;
+        }
 
         self.black.interface.notify_restart().await
             .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
@@ -105,9 +541,6 @@ where
         self.white.interface.notify_restart().await
             .map_err(|error| Error::NotifyError { error, color: self.white.color })?;
 
-        self.black.interface.notify_begin().await
-            .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
-
         Ok(())
     }
 }
@@ -119,84 +552,96 @@ where
 {
     async fn run(
         &mut self,
-    ) -> Result<GameEnd, Error>
+    ) -> Result<MatchSummary, Error>
     {
         let (actions_tx, mut actions_rx) = channel::<(PlayerColor, PlayerAction)>(10000);
         let (actions_tx_black, actions_tx_white) = (actions_tx.clone(), actions_tx.clone());
         
         // Start listening to players.
-        let mut listeners = JoinSet::new();
+        let mut listeners = Listeners::new();
 
         let black_interface = self.black.interface.clone();
-        listeners.spawn(async move { black_interface.listen(PlayerColor::Black, actions_tx_black).await });
+        listeners.spawn(async move {
+            (PlayerColor::Black, black_interface.listen(PlayerColor::Black, actions_tx_black).await)
+        });
 
         let white_interface = self.white.interface.clone();
-        listeners.spawn(async move { white_interface.listen(PlayerColor::White, actions_tx_white).await });
+        listeners.spawn(async move {
+            (PlayerColor::White, white_interface.listen(PlayerColor::White, actions_tx_white).await)
+        });
         
-        self.start_game().await?;
+        if let Err(error) = self.start_game().await {
+            return self.abort(error.into_startup_failure());
+        }
+
+        // Every game opens with a handshake, the clocks only running once it
+        // is over.
+        let mut opening = true;
 
         loop {
-            tokio::select! {
-                Some((color, action)) = actions_rx.recv() => {
-                    tracing::debug!("received {:?} from {}", action, color);
-
-                    match action {
-                        PlayerAction::Ready => {                    
-                            self.handle_ready(color).await?;
-                        },
-                        PlayerAction::Play(position) => {
-                            if let Some(end) = self.handle_play(color, position).await? {
-                                match self.mode {
-                                    Mode::SingleGame => {
-                                        self.end_game().await?;
-
-                                        return Ok(end)
-                                    },
-                                    Mode::Loop => self.restart_game().await?,
-                                }
-                            }
-                        },
-                        PlayerAction::Metadata(metadata) => {
-                            self.handle_metadata(color, metadata).await?;
-                        },
-                        PlayerAction::Unknown(content) => {
-                            self.handle_unknown(color, content).await?;
-                        },
-                        PlayerAction::Error(content) => {
-                            self.handle_error(color, content).await?;
-                        },
-                        PlayerAction::Message(content) => {
-                            self.handle_message(color, content).await?;
-                        },
-                        PlayerAction::Debug(content) => {
-                            self.handle_debug(color, content).await?;
-                        },
-                        PlayerAction::Suggestion(position) => {
-                            self.handle_suggestion(color, position).await?;
-                        },
-                    }
-                },
-                res = self.game.run_timers() => {
-                    match res {
-                        Ok(end) => {
-                            return Ok(end)
-                        },
-                        Err(e) => return Err(e.into()),
-                    }
-                },
-                Some(res) = listeners.join_next() => {
-                    match res {
-                        Err(e) => return Err(e.into()),
-                        Ok(listener_res) => match listener_res {
-                            Err(e)=> return Err(e.into()),
-                            Ok(_) => continue,
+            let step = if opening {
+                opening = false;
+
+                self.open_game(&mut actions_rx, &mut listeners).await.map(|()| None)
+            } else {
+                tokio::select! {
+                    Some((color, action)) = actions_rx.recv() => {
+                        // Listeners tag actions with the color their
+                        // interface was created for.
+                        let color = self.seat_color(color);
+
+                        tracing::debug!("received {:?} from {}", action, color);
+
+                        self.handle_action(color, action).await
+                    },
+                    res = self.game.run_timers() => {
+                        res.map(|end| Some(end.into())).map_err(Error::from)
+                    },
+                    Some(command) = next_command(&mut self.commands) => {
+                        tracing::debug!("received command {:?}", command);
+
+                        self.handle_command(command).await
+                    },
+                    Some(res) = listeners.join_next() => {
+                        match self.listener_error(res) {
+                            error @ Error::JoinError(_) => return Err(error),
+                            error => Err(error),
                         }
+                    },
+                    else => {
+                        return Err(Error::ChannelClosed);
                     }
-                },
-                else => {
-                    return Err(Error::ChannelClosed);
                 }
+            };
+
+            // Errors caused by a player make it lose the game.
+            let result = match step {
+                Ok(None) => continue,
+                Ok(Some(result)) => result,
+                Err(error) => match GameResult::try_from(error) {
+                    Ok(result) => result,
+                    Err(error) => return self.abort(error),
+                },
+            };
+            let result = self.credit(result);
+
+            // Errors once the game is recorded only stop the coordination.
+            match self.finish_game(result).await {
+                Ok(true) => return Ok(std::mem::take(&mut self.summary)),
+                Ok(false) => opening = true,
+                Err(error) => return self.abort(error),
             }
         }
     }
 }
+
+/// Waits for the next command, forever if no sender was requested.
+async fn next_command(
+    commands: &mut Option<Receiver<Command>>,
+) -> Option<Command>
+{
+    match commands {
+        Some(commands_rx) => commands_rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
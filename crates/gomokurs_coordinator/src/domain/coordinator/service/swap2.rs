@@ -0,0 +1,192 @@
+//! Handlers of the Swap2 opening phase.
+//!
+//! The player whose interface plays black when the game starts places the
+//! first three stones. The other player then either chooses a color or places
+//! two more stones and lets the first player choose. Colors are swapped
+//! between the players as soon as they are decided.
+//!
+//! Each decision is timed on the clock of the player making it, even when
+//! the next stone is of its opponent's color. Clocks are exchanged along with
+//! the colors, so that each player keeps the time it spent.
+
+use crate::domain::coordinator::ports::PlayerInterface;
+use gomokurs_game_engine::domain::game_engine::ports::GameEngineService;
+use crate::domain::coordinator::service::service::Service;
+use crate::domain::coordinator::models::*;
+
+impl<G, I> Service<G, I>
+where
+    G: GameEngineService,
+    I: PlayerInterface
+{
+    /// Starts the Swap2 opening by asking the first player to place three
+    /// stones.
+    pub async fn start_swap2(
+        &mut self,
+    ) -> Result<(), Error>
+    {
+        self.swap2 = Some(Swap2Phase::Placement);
+
+        self.black.interface
+            .notify_swap2_board(Vec::new())
+            .await
+            .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
+
+        Ok(())
+    }
+
+    pub async fn handle_place_stones(
+        &mut self,
+        color: PlayerColor,
+        stones: Vec<Position>,
+    ) -> Result<(), Error>
+    {
+        match (self.swap2, color, stones.len()) {
+            (Some(Swap2Phase::Placement), PlayerColor::Black, 3) => {
+                self.place_opening_stones(color, stones).await?;
+                self.swap2 = Some(Swap2Phase::SecondPlayerChoice);
+
+                self.white.interface
+                    .notify_swap2_board(self.opening_stones().await)
+                    .await
+                    .map_err(|error| Error::NotifyError { error, color: self.white.color })?;
+            },
+            (Some(Swap2Phase::SecondPlayerChoice), PlayerColor::White, 2) => {
+                self.place_opening_stones(color, stones).await?;
+                self.swap2 = Some(Swap2Phase::FirstPlayerChoice);
+
+                // The first player decides while white is to move.
+                self.game.swap_clocks().await;
+
+                self.black.interface
+                    .notify_swap2_board(self.opening_stones().await)
+                    .await
+                    .map_err(|error| Error::NotifyError { error, color: self.black.color })?;
+            },
+            _ => self.reject_swap2_action(color, "unexpected stones placement").await?,
+        }
+
+        Ok(())
+    }
+
+    pub async fn handle_swap(
+        &mut self,
+        color: PlayerColor,
+    ) -> Result<(), Error>
+    {
+        match (self.swap2, color) {
+            // The second player takes black, the first player plays the
+            // fourth stone as white.
+            (Some(Swap2Phase::SecondPlayerChoice), PlayerColor::White) => {
+                self.swap_colors();
+                self.game.swap_clocks().await;
+                self.end_swap2().await?;
+            },
+            // The first player keeps black, the second player plays the
+            // sixth stone as white.
+            (Some(Swap2Phase::FirstPlayerChoice), PlayerColor::Black) => {
+                self.game.swap_clocks().await;
+                self.end_swap2().await?;
+            },
+            _ => self.reject_swap2_action(color, "unexpected swap").await?,
+        }
+
+        Ok(())
+    }
+
+    /// Handles a move played while a Swap2 decision is pending, meaning the
+    /// player chooses white and plays its next stone.
+    pub async fn handle_swap2_play(
+        &mut self,
+        color: PlayerColor,
+        position: Position,
+    ) -> Result<(), Error>
+    {
+        match (self.swap2, color) {
+            (Some(Swap2Phase::SecondPlayerChoice), PlayerColor::White) => {
+                self.place_opening_stones(PlayerColor::White, vec![position]).await?;
+                self.end_swap2().await?;
+            },
+            // The white stone is timed on the clock of the first player,
+            // which already runs as white.
+            (Some(Swap2Phase::FirstPlayerChoice), PlayerColor::Black) => {
+                self.place_opening_stones(PlayerColor::Black, vec![position]).await?;
+                self.swap_colors();
+                self.end_swap2().await?;
+            },
+            _ => self.reject_swap2_action(color, "unexpected move during swap2 opening").await?,
+        }
+
+        Ok(())
+    }
+
+    /// Registers the stones placed by a player during the opening, each
+    /// stone alternating color starting from the next color to play. If a
+    /// stone is illegal, none of them is kept.
+    async fn place_opening_stones(
+        &mut self,
+        color: PlayerColor,
+        stones: Vec<Position>,
+    ) -> Result<(), Error>
+    {
+        for (placed, stone) in stones.into_iter().enumerate() {
+            let stone_color = self.game.get_turn_player().await;
+
+            if let Err(e) = self.game.register_player_move(stone_color, stone).await {
+                for _ in 0..placed {
+                    self.game.undo().await?;
+                }
+
+                let player = self.player(color);
+
+                player.interface
+                    .notify_error(&e.to_string())
+                    .await
+                    .map_err(|error| Error::NotifyError { error, color: player.color })?;
+
+                return Err(Error::IllegalMove { color, position: stone, error: e });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ends the opening and asks the player to move for its next stone.
+    async fn end_swap2(
+        &mut self,
+    ) -> Result<(), Error>
+    {
+        self.swap2 = None;
+        self.black.board_synced = false;
+        self.white.board_synced = false;
+
+        let next_color = self.game.get_turn_player().await;
+
+        self.sync_board(next_color).await
+    }
+
+    /// Returns the stones placed so far, in the order they were played.
+    async fn opening_stones(
+        &self,
+    ) -> Vec<Position>
+    {
+        self.game.get_moves().await
+            .iter()
+            .map(|recorded_move| recorded_move.position)
+            .collect()
+    }
+
+    async fn reject_swap2_action(
+        &self,
+        color: PlayerColor,
+        content: &str,
+    ) -> Result<(), Error>
+    {
+        let player = self.player(color);
+
+        player.interface
+            .notify_error(content)
+            .await
+            .map_err(|error| Error::NotifyError { error, color: player.color })
+    }
+}
@@ -0,0 +1,9 @@
+//! Plays tournaments between many engines, each pairing of engines playing a
+//! match run by the coordinator.
+
+pub mod models;
+pub mod ports;
+pub mod service;
+
+pub use service::{CreateTournamentConfiguration, Service as Tournament};
+pub use ports::TournamentService;
@@ -0,0 +1,9 @@
+//! Define the models of the tournament service.
+
+pub mod schedule;
+pub mod report;
+pub mod error;
+
+pub use schedule::*;
+pub use report::*;
+pub use error::*;
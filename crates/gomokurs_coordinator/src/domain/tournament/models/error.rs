@@ -0,0 +1,26 @@
+//! Errors of the tournament service.
+
+use crate::domain::coordinator::models::Error as CoordinatorError;
+use thiserror::Error;
+
+/// Errors returned by the tournament service.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A tournament needs at least two entrants.
+    #[error("a tournament needs at least two entrants, got `{0}`")]
+    NotEnoughEntrants(usize),
+}
+
+/// Errors preventing the match of a pairing from being played to its end.
+#[derive(Debug, Error)]
+pub enum PairingError {
+    /// The player interface of an entrant could not be created.
+    #[error("failed to create the interface of `{entrant}`: `{error}`")]
+    CreateInterface {
+        entrant: String,
+        error: anyhow::Error,
+    },
+    /// The coordinator failed to run the match.
+    #[error(transparent)]
+    Coordinator(#[from] CoordinatorError),
+}
@@ -0,0 +1,184 @@
+//! Models of the results of a tournament.
+
+use std::fmt;
+use std::path::PathBuf;
+use crate::domain::coordinator::models::{MatchSummary, Score};
+use crate::domain::tournament::models::schedule::Pairing;
+
+/// An engine taking part in a tournament.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entrant {
+    /// The name of the engine in the report.
+    pub name: String,
+    /// The folder the engine may keep persistent files in, if any.
+    pub folder: Option<PathBuf>,
+}
+
+/// The result of the match of a pairing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PairingResult {
+    /// The pairing of the match.
+    pub pairing: Pairing,
+    /// The games played, the first engine being the first entrant.
+    pub summary: MatchSummary,
+    /// The reason the match could not be played to its end, if any.
+    pub error: Option<String>,
+}
+
+impl PairingResult {
+    /// Returns the score of an entrant in the match, `None` if it did not
+    /// take part in it.
+    pub fn score(
+        &self,
+        entrant: usize,
+    ) -> Option<Score>
+    {
+        if entrant == self.pairing.first {
+            Some(self.summary.first.total())
+        } else if entrant == self.pairing.second {
+            Some(self.summary.second.total())
+        } else {
+            None
+        }
+    }
+}
+
+/// The rank of an entrant in a tournament.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standing {
+    /// The index of the entrant.
+    pub entrant: usize,
+    /// The score of the entrant over the whole tournament.
+    pub score: Score,
+}
+
+/// The results of a tournament.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TournamentReport {
+    /// The entrants of the tournament.
+    pub entrants: Vec<Entrant>,
+    /// The results of the matches, in the order they were played.
+    pub pairings: Vec<PairingResult>,
+}
+
+impl TournamentReport {
+    /// Returns the score of an entrant against an opponent, over all their
+    /// matches.
+    pub fn score(
+        &self,
+        entrant: usize,
+        opponent: usize,
+    ) -> Score
+    {
+        self.pairings
+            .iter()
+            .filter(|result| result.score(opponent).is_some())
+            .filter_map(|result| result.score(entrant))
+            .fold(Score::default(), |total, score| total + score)
+    }
+
+    /// Returns the score of every entrant against every other entrant,
+    /// indexed by entrant then opponent.
+    pub fn crosstable(
+        &self,
+    ) -> Vec<Vec<Score>>
+    {
+        (0..self.entrants.len())
+            .map(|entrant| {
+                (0..self.entrants.len())
+                    .map(|opponent| {
+                        if entrant == opponent {
+                            Score::default()
+                        } else {
+                            self.score(entrant, opponent)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Returns the entrants ranked by points, then by wins.
+    pub fn standings(
+        &self,
+    ) -> Vec<Standing>
+    {
+        let mut standings: Vec<Standing> = (0..self.entrants.len())
+            .map(|entrant| Standing {
+                entrant,
+                score: self.pairings
+                    .iter()
+                    .filter_map(|result| result.score(entrant))
+                    .fold(Score::default(), |total, score| total + score),
+            })
+            .collect();
+
+        // Half points are compared as whole numbers to keep the order total.
+        standings.sort_by_key(|standing| {
+            let score = standing.score;
+
+            std::cmp::Reverse((2 * score.wins + score.draws, score.wins))
+        });
+
+        standings
+    }
+}
+
+impl fmt::Display for TournamentReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.entrants
+            .iter()
+            .map(|entrant| entrant.name.len())
+            .fold("engine".len(), usize::max);
+
+        writeln!(f, "pairings:")?;
+        for result in &self.pairings {
+            let (first, second) = (result.pairing.first, result.pairing.second);
+
+            write!(
+                f,
+                "  round {:>2}: {} - {}: {}",
+                result.pairing.round + 1,
+                self.entrants[first].name,
+                self.entrants[second].name,
+                result.score(first).unwrap_or_default(),
+            )?;
+            match &result.error {
+                Some(error) => writeln!(f, " ({})", error)?,
+                None => writeln!(f)?,
+            }
+        }
+
+        writeln!(f, "crosstable:")?;
+        write!(f, "  {:>3}  {:<width$}", "#", "engine")?;
+        for opponent in 1..=self.entrants.len() {
+            write!(f, " {:>5}", opponent)?;
+        }
+        writeln!(f)?;
+        for (entrant, row) in self.crosstable().iter().enumerate() {
+            write!(f, "  {:>3}  {:<width$}", entrant + 1, self.entrants[entrant].name)?;
+            for (opponent, score) in row.iter().enumerate() {
+                if entrant == opponent || score.games() == 0 {
+                    write!(f, " {:>5}", "-")?;
+                } else {
+                    write!(f, " {:>5.1}", score.points())?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        writeln!(f, "standings:")?;
+        for (rank, standing) in self.standings().iter().enumerate() {
+            writeln!(
+                f,
+                "  {:>3}. {:<width$} {:>5.1} ({})",
+                rank + 1,
+                self.entrants[standing.entrant].name,
+                standing.score.points(),
+                standing.score,
+            )?;
+        }
+
+        Ok(())
+    }
+}
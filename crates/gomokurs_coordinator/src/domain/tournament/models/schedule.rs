@@ -0,0 +1,121 @@
+//! Models of the schedule of a tournament.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Defines how many times the entrants of a tournament meet each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    /// Every entrant meets every other entrant once.
+    #[default]
+    RoundRobin,
+    /// Every entrant meets every other entrant twice, the first color of
+    /// the second meeting being reversed.
+    DoubleRoundRobin,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Format::RoundRobin => write!(f, "round-robin"),
+            Format::DoubleRoundRobin => write!(f, "double-round-robin"),
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "round-robin" => Ok(Format::RoundRobin),
+            "double-round-robin" => Ok(Format::DoubleRoundRobin),
+            _ => Err(ParseFormatError::UnknownFormat(s.to_string())),
+        }
+    }
+}
+
+/// Errors that may occur while parsing a tournament format from its textual
+/// representation.
+#[derive(Debug, Error)]
+pub enum ParseFormatError {
+    /// The format is not supported by the tournament service.
+    #[error("unknown tournament format `{0}`, expected `round-robin` or `double-round-robin`")]
+    UnknownFormat(String),
+}
+
+/// A match between two entrants, given by their index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pairing {
+    /// The round of the tournament the match is played in, from zero.
+    pub round: usize,
+    /// The entrant playing black in the first game of the match.
+    pub first: usize,
+    /// The entrant playing white in the first game of the match.
+    pub second: usize,
+}
+
+/// Schedules the matches of a tournament in rounds, each entrant playing at
+/// most once per round.
+///
+/// Rounds follow the circle method: one entrant stays in place while the
+/// others rotate around it, an odd number of entrants giving each of them a
+/// round off in turn. Each entrant of the circle plays first against the
+/// half of the circle following it, and the entrant in place every other
+/// round, so that each entrant plays first in as many matches as second,
+/// give or take one.
+///
+/// # Arguments
+///
+/// * `entrants` - The number of entrants.
+/// * `format` - The format of the tournament.
+pub fn schedule(
+    entrants: usize,
+    format: Format,
+) -> Vec<Pairing>
+{
+    // An odd number of entrants gets a phantom entrant, whose opponent has
+    // a round off.
+    let seats = entrants + entrants % 2;
+    let rounds = seats.saturating_sub(1);
+    let mut pairings = Vec::new();
+
+    for round in 0..rounds {
+        for board in 0..seats / 2 {
+            let (a, b) = match board {
+                0 => (seats - 1, round),
+                _ => ((round + board) % rounds, (round + rounds - board) % rounds),
+            };
+
+            if a >= entrants || b >= entrants {
+                continue;
+            }
+
+            // `a` follows `b` on the circle by twice the board number, `b`
+            // playing first when this is within half of the circle.
+            let a_first = match board {
+                0 => round % 2 == 0,
+                _ => 2 * board > rounds / 2,
+            };
+            let (first, second) = if a_first { (a, b) } else { (b, a) };
+
+            pairings.push(Pairing { round, first, second });
+        }
+    }
+
+    if format == Format::DoubleRoundRobin {
+        let return_pairings: Vec<Pairing> = pairings
+            .iter()
+            .map(|pairing| Pairing {
+                round: pairing.round + rounds,
+                first: pairing.second,
+                second: pairing.first,
+            })
+            .collect();
+
+        pairings.extend(return_pairings);
+    }
+
+    pairings
+}
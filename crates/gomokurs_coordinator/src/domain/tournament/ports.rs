@@ -0,0 +1,7 @@
+//! Define the ports required by the tournament service.
+
+pub mod service;
+pub mod entrant_factory;
+
+pub use service::TournamentService;
+pub use entrant_factory::EntrantFactory;
@@ -0,0 +1,27 @@
+//! EntrantFactory Port Definition
+//!
+//! This module defines the EntrantFactory port, responsible for creating the
+//! player interfaces of the entrants of a tournament.
+
+use crate::domain::coordinator::ports::PlayerInterface;
+use crate::domain::tournament::models::Entrant;
+
+/// Creates the player interfaces of the entrants of a tournament, a new one
+/// for each of their matches.
+pub trait EntrantFactory
+{
+    /// The player interface created for the entrants.
+    type Interface: PlayerInterface;
+
+    /// Creates the player interface of an entrant.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the entrant in the tournament.
+    /// * `entrant` - The entrant.
+    fn create_interface(
+        &self,
+        index: usize,
+        entrant: &Entrant,
+    ) -> impl std::future::Future<Output = Result<Self::Interface, anyhow::Error>>;
+}
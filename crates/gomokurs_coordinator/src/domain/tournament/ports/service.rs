@@ -0,0 +1,19 @@
+//! TournamentService Port Definition
+//!
+//! This module defines the TournamentService port, responsible for playing
+//! the matches of a tournament and reporting their results.
+
+use crate::domain::tournament::models::{Error, TournamentReport};
+
+/// A service that plays tournaments between many engines.
+pub trait TournamentService
+{
+    /// Plays every match of the tournament, one after another.
+    ///
+    /// # Returns
+    /// The results of the tournament. A match that could not be played to
+    /// its end is reported with the games played and the reason it stopped.
+    fn run(
+        &mut self,
+    ) -> impl std::future::Future<Output = Result<TournamentReport, Error>>;
+}
@@ -0,0 +1,158 @@
+//! Implementation of the tournament service.
+//!
+//! This service schedules the matches of a tournament and plays them one
+//! after another, each with a new coordinator and new player interfaces, so
+//! that an engine failing a match does not carry over to the next one.
+
+use crate::domain::coordinator::{Coordinator, CoordinatorService, CreateCoordinatorConfiguration};
+use crate::domain::coordinator::models::{Error as CoordinatorError, GameType, IllegalMovePolicy, MatchSummary, Mode, Opening};
+use crate::domain::tournament::ports::{EntrantFactory, TournamentService};
+use crate::domain::tournament::models::*;
+use gomokurs_game_engine::domain::game_engine::{CreateGameEngineConfiguration, GameEngine};
+use std::sync::Arc;
+use tokio::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct CreateTournamentConfiguration<F>
+where
+    F: EntrantFactory,
+{
+    pub entrants: Vec<Entrant>,
+    pub entrant_factory: F,
+    pub format: Format,
+    /// The number of games of each match, the engines alternating colors.
+    pub games_per_pairing: u32,
+    /// The configuration of the game engine of every game.
+    pub game_engine: CreateGameEngineConfiguration,
+    pub opening: Opening,
+    pub illegal_move_policy: IllegalMovePolicy,
+    pub game_type: GameType,
+    pub max_memory: u64,
+    pub startup_timeout: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct Service<F>
+where
+    F: EntrantFactory,
+{
+    pub entrants: Vec<Entrant>,
+    pub entrant_factory: F,
+    pub format: Format,
+    pub games_per_pairing: u32,
+    pub game_engine: CreateGameEngineConfiguration,
+    pub opening: Opening,
+    pub illegal_move_policy: IllegalMovePolicy,
+    pub game_type: GameType,
+    pub max_memory: u64,
+    pub startup_timeout: Duration,
+}
+
+impl<F> Service<F>
+where
+    F: EntrantFactory,
+{
+    /// Creates a new instance of the tournament service.
+    pub fn new(
+        cfg: CreateTournamentConfiguration<F>,
+    ) -> Self {
+        Self {
+            entrants: cfg.entrants,
+            entrant_factory: cfg.entrant_factory,
+            format: cfg.format,
+            games_per_pairing: cfg.games_per_pairing,
+            game_engine: cfg.game_engine,
+            opening: cfg.opening,
+            illegal_move_policy: cfg.illegal_move_policy,
+            game_type: cfg.game_type,
+            max_memory: cfg.max_memory,
+            startup_timeout: cfg.startup_timeout,
+        }
+    }
+
+    /// Creates the player interface of an entrant.
+    async fn create_interface(
+        &self,
+        index: usize,
+    ) -> Result<Arc<F::Interface>, PairingError>
+    {
+        let entrant = &self.entrants[index];
+
+        self.entrant_factory
+            .create_interface(index, entrant)
+            .await
+            .map(Arc::new)
+            .map_err(|error| PairingError::CreateInterface { entrant: entrant.name.clone(), error })
+    }
+
+    /// Plays the match of a pairing, the first entrant playing black in the
+    /// first game.
+    async fn play_pairing(
+        &self,
+        pairing: Pairing,
+    ) -> Result<MatchSummary, PairingError>
+    {
+        let first_interface = self.create_interface(pairing.first).await?;
+        let second_interface = self.create_interface(pairing.second).await?;
+        let game_engine = GameEngine::new(self.game_engine.clone())
+            .map_err(CoordinatorError::from)?;
+
+        let mut coordinator = Coordinator::new(CreateCoordinatorConfiguration {
+            game_engine,
+            black_player_interface: first_interface,
+            white_player_interface: second_interface,
+            game_mode: Mode::Match(self.games_per_pairing),
+            opening: self.opening,
+            illegal_move_policy: self.illegal_move_policy,
+            game_type: self.game_type,
+            max_memory: self.max_memory,
+            black_player_folder: self.entrants[pairing.first].folder.clone(),
+            white_player_folder: self.entrants[pairing.second].folder.clone(),
+            startup_timeout: self.startup_timeout,
+            alternate_colors: true,
+        });
+
+        Ok(coordinator.run().await?)
+    }
+}
+
+impl<F> TournamentService for Service<F>
+where
+    F: EntrantFactory,
+{
+    async fn run(
+        &mut self,
+    ) -> Result<TournamentReport, Error>
+    {
+        if self.entrants.len() < 2 {
+            return Err(Error::NotEnoughEntrants(self.entrants.len()));
+        }
+
+        let mut pairings = Vec::new();
+
+        for pairing in schedule(self.entrants.len(), self.format) {
+            let (first, second) = (&self.entrants[pairing.first].name, &self.entrants[pairing.second].name);
+
+            tracing::info!("round {}: {} - {}", pairing.round + 1, first, second);
+
+            // A match stopped by an engine keeps the games played before,
+            // while a match failing otherwise has none to report.
+            let result = match self.play_pairing(pairing).await {
+                Ok(summary) => PairingResult { pairing, error: summary.aborted.clone(), summary },
+                Err(error) => PairingResult { pairing, summary: MatchSummary::default(), error: Some(error.to_string()) },
+            };
+
+            if let Some(error) = &result.error {
+                tracing::error!("match {} - {} failed: {}", first, second, error);
+            }
+
+            tracing::info!("match {} - {}: {}", first, second, result.summary);
+            pairings.push(result);
+        }
+
+        Ok(TournamentReport {
+            entrants: self.entrants.clone(),
+            pairings,
+        })
+    }
+}
@@ -0,0 +1,100 @@
+//! Tests of the colors alternating between the games of a match, played
+//! between scripted players.
+
+mod common;
+
+use common::{coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use std::sync::Arc;
+use tokio::time::{timeout, Duration};
+
+/// The moves of the winner, a vertical five on the first column.
+const FIVE: [Option<(u8, u8)>; 5] = [Some((0, 0)), Some((0, 1)), Some((0, 2)), Some((0, 3)), Some((0, 4))];
+
+/// The moves of the loser, on the last column.
+const SCATTERED: [Option<(u8, u8)>; 5] = [Some((14, 0)), Some((14, 2)), Some((14, 4)), Some((14, 6)), Some((14, 8))];
+
+/// Plays a match of two games alternating colors.
+///
+/// # Returns
+/// The summary of the match and whether the players ended up swapped.
+async fn play_match(
+    first: Arc<ScriptedPlayer>,
+    second: Arc<ScriptedPlayer>,
+    opening: Opening,
+) -> (MatchSummary, bool)
+{
+    let mut coordinator = coordinator(first, second, Rules::default());
+    coordinator.mode = Mode::Match(2);
+    coordinator.opening = opening;
+    coordinator.alternate_colors = true;
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    (summary, coordinator.swapped)
+}
+
+#[tokio::test]
+async fn colors_alternate_between_games() {
+    // The first engine wins both games, as black then as white.
+    let first = ScriptedPlayer::new(&[FIVE, FIVE].concat(), Vec::new());
+    let second = ScriptedPlayer::new(&[&SCATTERED[..4], &SCATTERED[..]].concat(), Vec::new());
+
+    let (summary, swapped) = play_match(first.clone(), second.clone(), Opening::Standard).await;
+
+    assert!(swapped);
+    assert_eq!(summary.games.len(), 2);
+    assert_eq!(summary.games[0].black_engine, Engine::First);
+    assert_eq!(summary.games[0].winner, Some(PlayerColor::Black));
+    assert_eq!(summary.games[1].black_engine, Engine::Second);
+    assert_eq!(summary.games[1].winner, Some(PlayerColor::White));
+    assert!(summary.games.iter().all(|result| result.winner_engine() == Some(Engine::First)));
+
+    let win = Score { wins: 1, ..Score::default() };
+    let loss = Score { losses: 1, ..Score::default() };
+
+    assert_eq!(summary.first, EngineScore { as_black: win, as_white: win });
+    assert_eq!(summary.second, EngineScore { as_black: loss, as_white: loss });
+
+    // The engine that played white in the first game begins the second.
+    assert_eq!(first.received()[..3], ["START 15", "BEGIN", "TURN 14,0"]);
+    assert!(first.received().contains(&"RESTART".to_string()));
+    assert_eq!(second.received().iter().filter(|command| *command == "BEGIN").count(), 1);
+    assert_eq!(second.received().iter().skip_while(|command| *command != "RESTART").nth(1).unwrap(), "BEGIN");
+}
+
+#[tokio::test]
+async fn swap2_swap_counts_as_the_alternation() {
+    let stones = || PlayerAction::PlaceStones([(7, 7), (7, 8), (8, 7)].map(|(x, y)| Position::new(x, y)).to_vec());
+
+    // The second engine takes black in the first game, then opens the
+    // second, where the first engine takes white. The first engine wins
+    // both games as white.
+    let first = ScriptedPlayer::swap2(
+        &[&FIVE[..], &FIVE[1..]].concat(),
+        vec![stones(), PlayerAction::Play(Position::new(0, 0))],
+    );
+    let second = ScriptedPlayer::swap2(
+        &[&SCATTERED[..4], &SCATTERED[..4]].concat(),
+        vec![PlayerAction::Swap, stones()],
+    );
+
+    let (summary, swapped) = play_match(first.clone(), second.clone(), Opening::Swap2).await;
+
+    // The swap of the first game already gave black to the engine that
+    // opened as white, so the seats are kept for the second game.
+    assert!(swapped);
+    assert_eq!(summary.games.len(), 2);
+    assert!(summary.games.iter().all(|result| result.black_engine == Engine::Second));
+    assert!(summary.games.iter().all(|result| result.winner_engine() == Some(Engine::First)));
+    assert_eq!(summary.first.as_white, Score { wins: 2, ..Score::default() });
+    assert_eq!(summary.second.as_black, Score { losses: 2, ..Score::default() });
+
+    // Each engine opens one of the games.
+    assert_eq!(first.received().iter().filter(|command| *command == "SWAP2BOARD 0").count(), 1);
+    assert_eq!(second.received().iter().skip_while(|command| *command != "RESTART").nth(1).unwrap(), "SWAP2BOARD 0");
+}
@@ -0,0 +1,321 @@
+//! A scripted player and a coordinator to run games between such players.
+
+// Each test only uses part of the helpers.
+#![allow(dead_code)]
+
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::ports::PlayerInterface;
+use gomokurs_coordinator::domain::coordinator::{Coordinator, CreateCoordinatorConfiguration};
+use gomokurs_game_engine::domain::game_engine::{CreateGameEngineConfiguration, GameEngine};
+use gomokurs_game_engine::domain::game_engine::models::TimeControl;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::Sender;
+use tokio::time::{sleep, Duration};
+
+/// The channel of the actions sent to the coordinator.
+type Actions = Sender<(PlayerColor, PlayerAction)>;
+
+/// The channel of the listener, and the actions sent before it started.
+#[derive(Debug, Default)]
+struct Listener
+{
+    channel: Option<(PlayerColor, Actions)>,
+    pending: Vec<PlayerAction>,
+}
+
+/// A player answering the coordinator from a script, and keeping the
+/// commands it received as written in the Gomocup protocol.
+#[derive(Debug, Default)]
+pub struct ScriptedPlayer
+{
+    /// The moves to play when asked to, or sent an error, `None` to stay
+    /// silent.
+    moves: Mutex<VecDeque<Option<Position>>>,
+    /// The actions sent when told about a takeback.
+    on_takeback: Vec<PlayerAction>,
+    /// The actions sent when asked for a Swap2 decision, one per request.
+    on_swap2: Mutex<VecDeque<PlayerAction>>,
+    /// Whether the player disconnects as soon as it is listened to.
+    disconnected: bool,
+    /// Whether the player answers the start of a game with an error.
+    refuses_start: bool,
+    /// Whether the player answers the restart of a game with an error.
+    refuses_restart: bool,
+    /// The commands received so far.
+    received: Mutex<Vec<String>>,
+    listener: Mutex<Listener>,
+}
+
+impl ScriptedPlayer
+{
+    pub fn new(
+        moves: &[Option<(u8, u8)>],
+        on_takeback: Vec<PlayerAction>,
+    ) -> Arc<Self>
+    {
+        Arc::new(Self {
+            moves: Mutex::new(moves.iter().map(|m| m.map(|(x, y)| Position::new(x, y))).collect()),
+            on_takeback,
+            ..Self::default()
+        })
+    }
+
+    /// A player answering the Swap2 opening with the given decisions.
+    pub fn swap2(
+        moves: &[Option<(u8, u8)>],
+        on_swap2: Vec<PlayerAction>,
+    ) -> Arc<Self>
+    {
+        let player = Arc::into_inner(Self::new(moves, Vec::new())).unwrap();
+
+        *player.on_swap2.lock().unwrap() = on_swap2.into();
+
+        Arc::new(player)
+    }
+
+    /// A player disconnecting before answering anything.
+    pub fn disconnected() -> Arc<Self>
+    {
+        Arc::new(Self {
+            disconnected: true,
+            ..Self::default()
+        })
+    }
+
+    /// A player answering the start of a game with an error.
+    pub fn refusing_start() -> Arc<Self>
+    {
+        Arc::new(Self {
+            refuses_start: true,
+            ..Self::default()
+        })
+    }
+
+    /// A player answering the restart of a game with an error.
+    pub fn refusing_restart(
+        moves: &[Option<(u8, u8)>],
+    ) -> Arc<Self>
+    {
+        let mut player = Arc::into_inner(Self::new(moves, Vec::new())).unwrap();
+
+        player.refuses_restart = true;
+
+        Arc::new(player)
+    }
+
+    fn send(
+        &self,
+        action: PlayerAction,
+    )
+    {
+        let mut listener = self.listener.lock().unwrap();
+
+        match &listener.channel {
+            Some((color, tx)) => tx.try_send((*color, action)).unwrap(),
+            None => listener.pending.push(action),
+        }
+    }
+
+    fn receive(
+        &self,
+        command: String,
+    )
+    {
+        self.received.lock().unwrap().push(command);
+    }
+
+    fn play(
+        &self,
+    )
+    {
+        if let Some(Some(position)) = self.moves.lock().unwrap().pop_front() {
+            self.send(PlayerAction::Play(position));
+        }
+    }
+
+    pub fn received(
+        &self,
+    ) -> Vec<String>
+    {
+        self.received.lock().unwrap().clone()
+    }
+
+    pub async fn wait_for(
+        &self,
+        command: &str,
+    )
+    {
+        while !self.received().iter().any(|received| received == command) {
+            sleep(Duration::from_millis(10)).await;
+        }
+    }
+}
+
+impl PlayerInterface for ScriptedPlayer
+{
+    async fn listen(
+        &self,
+        color: PlayerColor,
+        tx: Actions,
+    ) -> Result<(), ListenError>
+    {
+        if self.disconnected {
+            return Err(ListenError::Disconnected);
+        }
+
+        {
+            let mut listener = self.listener.lock().unwrap();
+
+            for action in listener.pending.drain(..) {
+                tx.try_send((color, action)).unwrap();
+            }
+            listener.channel = Some((color, tx));
+        }
+
+        std::future::pending().await
+    }
+
+    async fn notify_start(&self, size: BoardSize) -> Result<(), NotifyError> {
+        if size.x == size.y {
+            self.receive(format!("START {}", size.x));
+        } else {
+            self.receive(format!("RECTSTART {},{}", size.x, size.y));
+        }
+        if self.refuses_start {
+            self.send(PlayerAction::Error("unsupported size".to_string()));
+        } else {
+            self.send(PlayerAction::Ready);
+        }
+
+        Ok(())
+    }
+
+    async fn notify_restart(&self) -> Result<(), NotifyError> {
+        self.receive("RESTART".to_string());
+        if self.refuses_restart {
+            self.send(PlayerAction::Error("cannot restart".to_string()));
+        } else {
+            self.send(PlayerAction::Ready);
+        }
+
+        Ok(())
+    }
+
+    async fn notify_turn(&self, position: Position) -> Result<(), NotifyError> {
+        self.receive(format!("TURN {}", position));
+        self.play();
+
+        Ok(())
+    }
+
+    async fn notify_takeback(&self, position: Position) -> Result<(), NotifyError> {
+        self.receive(format!("TAKEBACK {}", position));
+        for action in self.on_takeback.clone() {
+            self.send(action);
+        }
+
+        Ok(())
+    }
+
+    async fn notify_begin(&self) -> Result<(), NotifyError> {
+        self.receive("BEGIN".to_string());
+        self.play();
+
+        Ok(())
+    }
+
+    async fn notify_board(&self, turns: Vec<RelativeTurn>) -> Result<(), NotifyError> {
+        self.receive(format!("BOARD {}", turns.len()));
+        self.play();
+
+        Ok(())
+    }
+
+    async fn notify_swap2_board(&self, stones: Vec<Position>) -> Result<(), NotifyError> {
+        self.receive(format!("SWAP2BOARD {}", stones.len()));
+        let decision = self.on_swap2.lock().unwrap().pop_front();
+        if let Some(action) = decision {
+            self.send(action);
+        }
+
+        Ok(())
+    }
+
+    async fn notify_info(&self, _info: Information) -> Result<(), NotifyError> {
+        Ok(())
+    }
+
+    async fn notify_result(&self, _result: RelativeGameEnd) -> Result<(), NotifyError> {
+        Ok(())
+    }
+
+    async fn notify_end(&self) -> Result<(), NotifyError> {
+        self.receive("END".to_string());
+
+        Ok(())
+    }
+
+    async fn notify_about(&self) -> Result<(), NotifyError> {
+        Ok(())
+    }
+
+    async fn notify_unknown(&self, content: &str) -> Result<(), NotifyError> {
+        self.receive(format!("UNKNOWN {}", content));
+
+        Ok(())
+    }
+
+    async fn notify_error(&self, content: &str) -> Result<(), NotifyError> {
+        self.receive(format!("ERROR {}", content));
+        self.play();
+
+        Ok(())
+    }
+}
+
+/// Sets up a single game between scripted players on a 15x15 board, illegal
+/// moves being forfeits.
+pub fn coordinator(
+    black: Arc<ScriptedPlayer>,
+    white: Arc<ScriptedPlayer>,
+    rules: Rules,
+) -> Coordinator<GameEngine, ScriptedPlayer>
+{
+    sized_coordinator(black, white, rules, BoardSize { x: 15, y: 15 })
+}
+
+/// Sets up a single game between scripted players on a board of the given
+/// size, illegal moves being forfeits.
+pub fn sized_coordinator(
+    black: Arc<ScriptedPlayer>,
+    white: Arc<ScriptedPlayer>,
+    rules: Rules,
+    board_size: BoardSize,
+) -> Coordinator<GameEngine, ScriptedPlayer>
+{
+    let game_engine = GameEngine::new(CreateGameEngineConfiguration {
+        board_size,
+        turn_duration: Duration::from_secs(60),
+        match_duration: Duration::from_secs(600),
+        rules,
+        max_moves: None,
+        time_control: TimeControl::None,
+        byo_yomi: None,
+    }).unwrap();
+
+    Coordinator::new(CreateCoordinatorConfiguration {
+        game_engine,
+        black_player_interface: black,
+        white_player_interface: white,
+        game_mode: Mode::SingleGame,
+        opening: Opening::Standard,
+        illegal_move_policy: IllegalMovePolicy::Forfeit,
+        game_type: GameType::default(),
+        max_memory: 0,
+        black_player_folder: None,
+        white_player_folder: None,
+        startup_timeout: Duration::from_secs(5),
+        alternate_colors: false,
+    })
+}
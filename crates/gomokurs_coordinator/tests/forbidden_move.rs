@@ -0,0 +1,64 @@
+//! Tests of the forbidden moves of black under renju rules, played between
+//! scripted players.
+
+mod common;
+
+use common::{coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use gomokurs_game_engine::domain::game_engine::GameEngineService;
+use gomokurs_game_engine::domain::game_engine::models::RuleSet;
+use tokio::time::{timeout, Duration};
+
+/// Black builds a double three it is not allowed to complete at 7,7, while
+/// white makes a vertical five.
+async fn play_double_three(
+    forbidden_move_policy: ForbiddenMovePolicy,
+) -> (GameResult, Vec<Position>, Vec<String>)
+{
+    let black = ScriptedPlayer::new(
+        &[Some((5, 7)), Some((6, 7)), Some((7, 5)), Some((7, 6)), Some((7, 7)), Some((14, 14))],
+        Vec::new(),
+    );
+    let white = ScriptedPlayer::new(
+        &[Some((0, 0)), Some((0, 1)), Some((0, 2)), Some((0, 3)), Some((0, 4))],
+        Vec::new(),
+    );
+    let rules = Rules { rule_set: RuleSet::Renju, forbidden_move_policy, ..Rules::default() };
+    let mut coordinator = coordinator(black.clone(), white, rules);
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let moves = coordinator.game.get_record().await.moves
+        .iter()
+        .map(|recorded_move| recorded_move.position)
+        .collect();
+
+    (summary.games[0].clone(), moves, black.received())
+}
+
+#[tokio::test]
+async fn rejected_forbidden_move_is_played_again_without_forfeit() {
+    // Illegal moves are forfeits, which does not apply to forbidden moves.
+    let (result, moves, black) = play_double_three(ForbiddenMovePolicy::Reject).await;
+
+    assert_eq!(result.winner, Some(PlayerColor::White));
+    assert!(matches!(result.termination, Termination::Five(_)));
+    assert_eq!(result.offender, None);
+    assert!(!moves.contains(&Position::new(7, 7)));
+    assert_eq!(moves.last(), Some(&Position::new(0, 4)));
+    assert!(black.iter().any(|command| command.starts_with("ERROR")));
+}
+
+#[tokio::test]
+async fn forbidden_move_loses_the_game() {
+    let (result, moves, _) = play_double_three(ForbiddenMovePolicy::Loss).await;
+
+    assert_eq!(result.winner, Some(PlayerColor::White));
+    assert!(matches!(result.termination, Termination::IllegalMove { position, .. } if position == Position::new(7, 7)));
+    assert_eq!(result.offender, Some(PlayerColor::Black));
+    assert_eq!(moves.len(), 8);
+}
@@ -0,0 +1,60 @@
+//! Tests of the handshake opening every game, played between scripted
+//! players.
+
+mod common;
+
+use common::{coordinator, sized_coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use tokio::time::{timeout, Duration};
+
+#[tokio::test]
+async fn disconnection_before_the_game_is_not_a_forfeit() {
+    let black = ScriptedPlayer::new(&[Some((7, 7))], Vec::new());
+    let white = ScriptedPlayer::disconnected();
+    let mut coordinator = coordinator(black, white, Rules::default());
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(summary.games.is_empty());
+    assert!(summary.aborted.is_some_and(|reason| reason.starts_with("`white player` failed before the game began")));
+}
+
+#[tokio::test]
+async fn refused_restart_keeps_the_games_played() {
+    let black = ScriptedPlayer::new(&[Some((7, 7)), Some((8, 7)), Some((9, 7)), Some((10, 7)), Some((11, 7))], Vec::new());
+    let white = ScriptedPlayer::refusing_restart(&[Some((0, 0)), Some((0, 1)), Some((0, 2)), Some((0, 3))]);
+    let mut coordinator = coordinator(black, white, Rules::default());
+
+    coordinator.mode = Mode::Match(2);
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(summary.games.len(), 1);
+    assert_eq!(summary.games[0].winner, Some(PlayerColor::Black));
+    assert_eq!(summary.first.total().wins, 1);
+    assert!(summary.aborted.is_some_and(|reason| reason.starts_with("`white player` refused to start")));
+}
+
+#[tokio::test]
+async fn rectstart_answered_with_an_error_rejects_the_board() {
+    let black = ScriptedPlayer::new(&[Some((7, 7))], Vec::new());
+    let white = ScriptedPlayer::refusing_start();
+    let mut coordinator = sized_coordinator(black.clone(), white.clone(), Rules::default(), BoardSize { x: 20, y: 15 });
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(summary.games.is_empty());
+    assert_eq!(white.received()[0], "RECTSTART 20,15");
+    assert!(summary.aborted.is_some_and(|reason| reason.starts_with("`white player` refused to start a game on a `20x15` board")));
+    assert!(!black.received().contains(&"BEGIN".to_string()));
+}
@@ -0,0 +1,62 @@
+//! Tests of the score of a match of several games, played between scripted
+//! players on a 3x3 board where three in a row wins.
+
+mod common;
+
+use common::{sized_coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use tokio::time::{timeout, Duration};
+
+#[tokio::test]
+async fn match_scores_each_color() {
+    // Black wins the first game with the first row, the second game fills
+    // the board, and white wins the third game with the last row.
+    let black = ScriptedPlayer::new(
+        &[
+            Some((0, 0)), Some((1, 0)), Some((2, 0)),
+            Some((0, 0)), Some((2, 0)), Some((0, 1)), Some((1, 2)), Some((2, 2)),
+            Some((0, 0)), Some((1, 0)), Some((0, 1)),
+        ],
+        Vec::new(),
+    );
+    let white = ScriptedPlayer::new(
+        &[
+            Some((0, 1)), Some((1, 1)),
+            Some((1, 0)), Some((1, 1)), Some((2, 1)), Some((0, 2)),
+            Some((0, 2)), Some((1, 2)), Some((2, 2)),
+        ],
+        Vec::new(),
+    );
+    let rules = Rules { win_length: 3, ..Rules::default() };
+    let mut coordinator = sized_coordinator(black.clone(), white.clone(), rules, BoardSize { x: 3, y: 3 });
+    coordinator.mode = Mode::Match(3);
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let winners: Vec<Option<PlayerColor>> = summary.games.iter().map(|result| result.winner).collect();
+
+    assert_eq!(winners, [Some(PlayerColor::Black), None, Some(PlayerColor::White)]);
+    assert_eq!(summary.games[1].termination, Termination::Draw);
+    assert!(summary.games.iter().all(|result| result.black_engine == Engine::First));
+    assert_eq!(summary.aborted, None);
+
+    // Without alternating colors, each engine keeps its color.
+    let score = Score { wins: 1, losses: 1, draws: 1 };
+
+    assert_eq!(summary.first, EngineScore { as_black: score, as_white: Score::default() });
+    assert_eq!(summary.second, EngineScore { as_black: Score::default(), as_white: score });
+    assert_eq!(summary.score(Engine::First).total().points(), 1.5);
+    assert_eq!(
+        summary.to_string(),
+        "3 games, first engine: +1 -1 =1 (black +1 -1 =1, white +0 -0 =0), \
+         second engine: +1 -1 =1 (black +0 -0 =0, white +1 -1 =1)",
+    );
+
+    assert_eq!(black.received().iter().filter(|command| *command == "RESTART").count(), 2);
+    assert_eq!(black.received().last().unwrap(), "END");
+    assert_eq!(white.received().last().unwrap(), "END");
+}
@@ -0,0 +1,116 @@
+//! Tests of the crosstable and standings of a tournament, from hand-built
+//! match results.
+
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::tournament::models::*;
+
+/// The result of a game won by a color, or drawn, the first engine of the
+/// match playing black.
+fn game(
+    winner: Option<PlayerColor>,
+) -> GameResult
+{
+    GameResult {
+        winner,
+        termination: if winner.is_some() { Termination::Resignation } else { Termination::Draw },
+        offender: winner.map(|winner| winner.other()),
+        black_engine: Engine::First,
+    }
+}
+
+fn pairing_result(
+    round: usize,
+    first: usize,
+    second: usize,
+    games: &[Option<PlayerColor>],
+    error: Option<&str>,
+) -> PairingResult
+{
+    let mut summary = MatchSummary::default();
+
+    for winner in games {
+        summary.record(game(*winner));
+    }
+    summary.aborted = error.map(str::to_string);
+
+    PairingResult {
+        pairing: Pairing { round, first, second },
+        summary,
+        error: error.map(str::to_string),
+    }
+}
+
+/// Alpha draws both games against bravo and wins both against charlie,
+/// while charlie wins the single game played against bravo before the
+/// match was stopped.
+fn report() -> TournamentReport
+{
+    let entrants = ["alpha", "bravo", "charlie"]
+        .map(|name| Entrant { name: name.to_string(), folder: None })
+        .to_vec();
+
+    TournamentReport {
+        entrants,
+        pairings: vec![
+            pairing_result(0, 0, 1, &[None, None], None),
+            pairing_result(1, 1, 2, &[Some(PlayerColor::White)], Some("bravo crashed")),
+            pairing_result(2, 2, 0, &[Some(PlayerColor::White), Some(PlayerColor::White)], None),
+        ],
+    }
+}
+
+#[test]
+fn crosstable_scores_each_pair() {
+    let crosstable = report().crosstable();
+    let score = |wins, losses, draws| Score { wins, losses, draws };
+
+    assert_eq!(crosstable, [
+        [score(0, 0, 0), score(0, 0, 2), score(2, 0, 0)],
+        [score(0, 0, 2), score(0, 0, 0), score(0, 1, 0)],
+        [score(0, 2, 0), score(1, 0, 0), score(0, 0, 0)],
+    ]);
+}
+
+#[test]
+fn aborted_match_counts_the_games_played() {
+    let report = report();
+    let aborted = &report.pairings[1];
+
+    assert_eq!(aborted.score(1), Some(Score { losses: 1, ..Score::default() }));
+    assert_eq!(aborted.score(2), Some(Score { wins: 1, ..Score::default() }));
+    assert_eq!(aborted.score(0), None);
+    assert_eq!(report.score(2, 1).games(), 1);
+}
+
+#[test]
+fn wins_break_ties_on_points() {
+    let standings = report().standings();
+    let entrants: Vec<usize> = standings.iter().map(|standing| standing.entrant).collect();
+
+    // Bravo and charlie both score a point, bravo through draws only.
+    assert_eq!(entrants, [0, 2, 1]);
+    assert_eq!(standings[0].score, Score { wins: 2, losses: 0, draws: 2 });
+    assert_eq!(standings[1].score.points(), standings[2].score.points());
+    assert_eq!(standings[2].score, Score { wins: 0, losses: 1, draws: 2 });
+}
+
+#[test]
+fn report_lists_pairings_crosstable_and_standings() {
+    let expected = "\
+pairings:
+  round  1: alpha - bravo: +0 -0 =2
+  round  2: bravo - charlie: +0 -1 =0 (bravo crashed)
+  round  3: charlie - alpha: +0 -2 =0
+crosstable:
+    #  engine      1     2     3
+    1  alpha       -   1.0   2.0
+    2  bravo     1.0     -   0.0
+    3  charlie   0.0   1.0     -
+standings:
+    1. alpha     3.0 (+2 -0 =2)
+    2. charlie   1.0 (+1 -2 =0)
+    3. bravo     1.0 (+0 -1 =2)
+";
+
+    assert_eq!(report().to_string(), expected);
+}
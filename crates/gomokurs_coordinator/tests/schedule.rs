@@ -0,0 +1,98 @@
+//! Tests of the schedule of the matches of a tournament.
+
+use gomokurs_coordinator::domain::tournament::models::*;
+use std::collections::HashSet;
+
+#[test]
+fn round_robin_pairs_every_entrant_once() {
+    for entrants in 2..=10 {
+        let pairings = schedule(entrants, Format::RoundRobin);
+        let pairs: HashSet<(usize, usize)> = pairings
+            .iter()
+            .map(|pairing| (pairing.first.min(pairing.second), pairing.first.max(pairing.second)))
+            .collect();
+
+        assert_eq!(pairings.len(), entrants * (entrants - 1) / 2, "{} entrants", entrants);
+        assert_eq!(pairs.len(), pairings.len(), "{} entrants", entrants);
+        assert!(pairings.iter().all(|pairing| pairing.first != pairing.second));
+        assert!(pairings.iter().all(|pairing| pairing.first < entrants && pairing.second < entrants));
+    }
+}
+
+#[test]
+fn entrants_play_once_per_round() {
+    for entrants in 2..=10 {
+        let pairings = schedule(entrants, Format::RoundRobin);
+        let rounds = if entrants % 2 == 0 { entrants - 1 } else { entrants };
+
+        assert_eq!(pairings.iter().map(|pairing| pairing.round).max(), Some(rounds - 1));
+
+        for round in 0..rounds {
+            let playing: Vec<usize> = pairings
+                .iter()
+                .filter(|pairing| pairing.round == round)
+                .flat_map(|pairing| [pairing.first, pairing.second])
+                .collect();
+            let distinct: HashSet<usize> = playing.iter().copied().collect();
+
+            assert_eq!(distinct.len(), playing.len(), "round {} of {} entrants", round, entrants);
+            // With an odd number of entrants, a single one has the round off.
+            assert_eq!(playing.len(), entrants - entrants % 2, "round {} of {} entrants", round, entrants);
+        }
+    }
+}
+
+#[test]
+fn odd_entrants_each_have_one_round_off() {
+    for entrants in [3, 5, 7, 9] {
+        let pairings = schedule(entrants, Format::RoundRobin);
+
+        for entrant in 0..entrants {
+            let rounds_played = pairings
+                .iter()
+                .filter(|pairing| pairing.first == entrant || pairing.second == entrant)
+                .count();
+
+            assert_eq!(rounds_played, entrants - 1, "entrant {} of {}", entrant, entrants);
+        }
+    }
+}
+
+#[test]
+fn double_round_robin_reverses_the_first_colors() {
+    for entrants in 2..=9 {
+        let single = schedule(entrants, Format::RoundRobin);
+        let double = schedule(entrants, Format::DoubleRoundRobin);
+        let rounds = single.iter().map(|pairing| pairing.round).max().unwrap() + 1;
+        let colored: HashSet<(usize, usize)> = double.iter().map(|pairing| (pairing.first, pairing.second)).collect();
+
+        assert_eq!(double.len(), 2 * single.len());
+        assert_eq!(colored.len(), double.len(), "{} entrants", entrants);
+        assert_eq!(double[..single.len()], single[..]);
+
+        for (pairing, return_pairing) in single.iter().zip(&double[single.len()..]) {
+            assert_eq!(return_pairing.round, pairing.round + rounds);
+            assert_eq!((return_pairing.first, return_pairing.second), (pairing.second, pairing.first));
+        }
+    }
+}
+
+#[test]
+fn first_colors_are_balanced() {
+    for entrants in 2..=10 {
+        let pairings = schedule(entrants, Format::RoundRobin);
+
+        for entrant in 0..entrants {
+            let first = pairings.iter().filter(|pairing| pairing.first == entrant).count() as i64;
+            let second = pairings.iter().filter(|pairing| pairing.second == entrant).count() as i64;
+
+            assert!((first - second).abs() <= 1, "entrant {} of {}: {} - {}", entrant, entrants, first, second);
+        }
+    }
+}
+
+#[test]
+fn fewer_than_two_entrants_have_no_match() {
+    assert!(schedule(0, Format::RoundRobin).is_empty());
+    assert!(schedule(1, Format::DoubleRoundRobin).is_empty());
+}
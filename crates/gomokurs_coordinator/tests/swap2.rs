@@ -0,0 +1,120 @@
+//! Tests of the Swap2 opening, played between scripted players.
+
+mod common;
+
+use common::{coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use gomokurs_game_engine::domain::game_engine::GameEngineService;
+use std::sync::Arc;
+use tokio::time::{timeout, Duration};
+
+/// The first three stones, placed by the player starting as black.
+fn first_stones() -> PlayerAction
+{
+    PlayerAction::PlaceStones([(7, 7), (7, 8), (8, 7)].map(|(x, y)| Position::new(x, y)).to_vec())
+}
+
+/// The two more stones the second player may place.
+fn more_stones() -> PlayerAction
+{
+    PlayerAction::PlaceStones([(9, 9), (10, 10)].map(|(x, y)| Position::new(x, y)).to_vec())
+}
+
+/// Plays a Swap2 opening followed by a game, the winner making a vertical
+/// five on the first column against moves on the last one.
+///
+/// # Returns
+/// The result of the game, whether the players swapped colors, and the
+/// positions of the moves played.
+async fn play_swap2(
+    first: Arc<ScriptedPlayer>,
+    second: Arc<ScriptedPlayer>,
+) -> (GameResult, bool, Vec<Position>)
+{
+    let mut coordinator = coordinator(first, second, Rules::default());
+    coordinator.opening = Opening::Swap2;
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let moves = coordinator.game.get_record().await.moves
+        .iter()
+        .map(|recorded_move| recorded_move.position)
+        .collect();
+
+    (summary.games[0].clone(), coordinator.swapped, moves)
+}
+
+/// The moves of the winner, without the first one when it is played as
+/// part of the opening.
+const FIVE: [Option<(u8, u8)>; 5] = [Some((0, 0)), Some((0, 1)), Some((0, 2)), Some((0, 3)), Some((0, 4))];
+
+/// The moves of the loser.
+const SCATTERED: [Option<(u8, u8)>; 4] = [Some((14, 0)), Some((14, 2)), Some((14, 4)), Some((14, 6))];
+
+#[tokio::test]
+async fn second_player_takes_black() {
+    let first = ScriptedPlayer::swap2(&FIVE, vec![first_stones()]);
+    let second = ScriptedPlayer::swap2(&SCATTERED, vec![PlayerAction::Swap]);
+
+    let (result, swapped, moves) = play_swap2(first.clone(), second.clone()).await;
+
+    // The first player plays the fourth stone as white, and wins with it.
+    assert!(swapped);
+    assert_eq!(result.winner, Some(PlayerColor::White));
+    assert_eq!(result.engine(PlayerColor::White), Engine::First);
+    assert_eq!(moves[3], Position::new(0, 0));
+    assert_eq!(first.received()[..3], ["START 15", "SWAP2BOARD 0", "BOARD 3"]);
+    assert_eq!(second.received()[..3], ["START 15", "SWAP2BOARD 3", "BOARD 4"]);
+}
+
+#[tokio::test]
+async fn second_player_takes_white() {
+    let first = ScriptedPlayer::swap2(&SCATTERED, vec![first_stones()]);
+    let second = ScriptedPlayer::swap2(&FIVE[1..], vec![PlayerAction::Play(Position::new(0, 0))]);
+
+    let (result, swapped, moves) = play_swap2(first.clone(), second.clone()).await;
+
+    // The second player plays the fourth stone as white, and wins with it.
+    assert!(!swapped);
+    assert_eq!(result.winner, Some(PlayerColor::White));
+    assert_eq!(result.engine(PlayerColor::White), Engine::Second);
+    assert_eq!(moves[3], Position::new(0, 0));
+    assert_eq!(first.received()[..3], ["START 15", "SWAP2BOARD 0", "BOARD 4"]);
+    assert_eq!(second.received()[..2], ["START 15", "SWAP2BOARD 3"]);
+}
+
+#[tokio::test]
+async fn first_player_takes_white_after_two_more_stones() {
+    let first = ScriptedPlayer::swap2(&FIVE[1..], vec![first_stones(), PlayerAction::Play(Position::new(0, 0))]);
+    let second = ScriptedPlayer::swap2(&SCATTERED, vec![more_stones()]);
+
+    let (result, swapped, moves) = play_swap2(first.clone(), second.clone()).await;
+
+    // The first player plays the sixth stone as white, and wins with it.
+    assert!(swapped);
+    assert_eq!(result.winner, Some(PlayerColor::White));
+    assert_eq!(result.engine(PlayerColor::White), Engine::First);
+    assert_eq!(moves[..6], [(7, 7), (7, 8), (8, 7), (9, 9), (10, 10), (0, 0)].map(|(x, y)| Position::new(x, y)));
+    assert_eq!(first.received()[..3], ["START 15", "SWAP2BOARD 0", "SWAP2BOARD 5"]);
+    assert_eq!(second.received()[..3], ["START 15", "SWAP2BOARD 3", "BOARD 6"]);
+}
+
+#[tokio::test]
+async fn first_player_keeps_black_after_two_more_stones() {
+    let first = ScriptedPlayer::swap2(&SCATTERED, vec![first_stones(), PlayerAction::Swap]);
+    let second = ScriptedPlayer::swap2(&FIVE, vec![more_stones()]);
+
+    let (result, swapped, moves) = play_swap2(first.clone(), second.clone()).await;
+
+    // The second player plays the sixth stone as white, and wins with it.
+    assert!(!swapped);
+    assert_eq!(result.winner, Some(PlayerColor::White));
+    assert_eq!(result.engine(PlayerColor::White), Engine::Second);
+    assert_eq!(moves[5], Position::new(0, 0));
+    assert_eq!(first.received()[..3], ["START 15", "SWAP2BOARD 0", "SWAP2BOARD 5"]);
+    assert_eq!(second.received()[..3], ["START 15", "SWAP2BOARD 3", "BOARD 5"]);
+}
@@ -0,0 +1,74 @@
+//! Tests of the takeback command, played between scripted players.
+
+mod common;
+
+use common::{coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use gomokurs_game_engine::domain::game_engine::GameEngineService;
+use std::sync::Arc;
+use tokio::time::{timeout, Duration};
+
+/// Black plays a move it takes back while white thinks, then wins with a
+/// horizontal five. White answers the takeback with the move it was
+/// thinking over.
+async fn play_takeback(
+    on_takeback: Vec<PlayerAction>,
+) -> (MatchSummary, Vec<Position>, Arc<ScriptedPlayer>, Arc<ScriptedPlayer>)
+{
+    let black = ScriptedPlayer::new(
+        &[Some((0, 0)), Some((7, 7)), Some((8, 7)), Some((9, 7)), Some((10, 7)), Some((11, 7))],
+        Vec::new(),
+    );
+    let white = ScriptedPlayer::new(
+        &[None, Some((0, 1)), Some((0, 2)), Some((0, 3)), Some((0, 4))],
+        on_takeback,
+    );
+    let mut coordinator = coordinator(black.clone(), white.clone(), Rules::default());
+    let commands = coordinator.command_sender();
+
+    let (summary, ()) = timeout(Duration::from_secs(10), async {
+        tokio::join!(coordinator.run(), async {
+            white.wait_for("TURN 0,0").await;
+            commands.send(Command::Takeback).await.unwrap();
+        })
+    })
+    .await
+    .unwrap();
+
+    let moves = coordinator.game.get_record().await.moves
+        .iter()
+        .map(|recorded_move| recorded_move.position)
+        .collect();
+
+    (summary.unwrap(), moves, black, white)
+}
+
+#[tokio::test]
+async fn takeback_replays_the_move() {
+    let (summary, moves, black, white) = play_takeback(vec![
+        PlayerAction::Play(Position::new(14, 14)),
+        PlayerAction::Ready,
+    ]).await;
+
+    assert_eq!(summary.games.len(), 1);
+    assert_eq!(summary.games[0].winner, Some(PlayerColor::Black));
+    assert_eq!(moves, [(7, 7), (0, 1), (8, 7), (0, 2), (9, 7), (0, 3), (10, 7), (0, 4), (11, 7)].map(|(x, y)| Position::new(x, y)));
+
+    // Only the opponent is told about the takeback, the player being sent
+    // the board to play again.
+    assert_eq!(black.received(), ["START 15", "BEGIN", "BOARD 0", "TURN 0,1", "TURN 0,2", "TURN 0,3", "TURN 0,4", "END"]);
+    assert_eq!(white.received(), ["START 15", "TURN 0,0", "TAKEBACK 0,0", "TURN 7,7", "TURN 8,7", "TURN 9,7", "TURN 10,7", "END"]);
+}
+
+#[tokio::test]
+async fn refused_takeback_resyncs_the_board() {
+    let (summary, moves, _, white) = play_takeback(vec![
+        PlayerAction::Play(Position::new(14, 14)),
+        PlayerAction::Unknown("TAKEBACK".to_string()),
+    ]).await;
+
+    assert_eq!(summary.games[0].winner, Some(PlayerColor::Black));
+    assert_eq!(moves.len(), 9);
+    assert_eq!(white.received(), ["START 15", "TURN 0,0", "TAKEBACK 0,0", "BOARD 1", "TURN 8,7", "TURN 9,7", "TURN 10,7", "END"]);
+}
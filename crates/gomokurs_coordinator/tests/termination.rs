@@ -0,0 +1,65 @@
+//! Tests of the games ended by a resignation or an adjudication, played
+//! between scripted players.
+
+mod common;
+
+use common::{coordinator, ScriptedPlayer};
+use gomokurs_coordinator::domain::coordinator::models::*;
+use gomokurs_coordinator::domain::coordinator::CoordinatorService;
+use gomokurs_game_engine::domain::game_engine::{CreateGameEngineConfiguration, GameEngine};
+use gomokurs_game_engine::domain::game_engine::models::TimeControl;
+use tokio::time::{timeout, Duration};
+
+#[tokio::test]
+async fn resigning_forfeits_the_game() {
+    // White stays silent, resigning instead of answering black's move.
+    let black = ScriptedPlayer::new(&[Some((7, 7))], Vec::new());
+    let white = ScriptedPlayer::new(&[None], Vec::new());
+    let mut coordinator = coordinator(black.clone(), white.clone(), Rules::default());
+    let commands = coordinator.command_sender();
+
+    let (summary, ()) = timeout(Duration::from_secs(10), async {
+        tokio::join!(coordinator.run(), async {
+            white.wait_for("TURN 7,7").await;
+            commands.send(Command::Resign(PlayerColor::White)).await.unwrap();
+        })
+    })
+    .await
+    .unwrap();
+
+    let result = &summary.unwrap().games[0];
+
+    assert_eq!(result.winner, Some(PlayerColor::Black));
+    assert_eq!(result.termination, Termination::Resignation);
+    assert_eq!(result.offender, Some(PlayerColor::White));
+    assert_eq!(black.received(), ["START 15", "BEGIN", "END"]);
+    assert_eq!(white.received(), ["START 15", "TURN 7,7", "END"]);
+}
+
+#[tokio::test]
+async fn move_limit_is_adjudicated() {
+    let black = ScriptedPlayer::new(&[Some((7, 7)), Some((8, 8))], Vec::new());
+    let white = ScriptedPlayer::new(&[Some((0, 0)), Some((0, 1))], Vec::new());
+    let mut coordinator = coordinator(black, white, Rules::default());
+
+    coordinator.game = GameEngine::new(CreateGameEngineConfiguration {
+        board_size: BoardSize { x: 15, y: 15 },
+        turn_duration: Duration::from_secs(60),
+        match_duration: Duration::from_secs(600),
+        rules: Rules::default(),
+        max_moves: Some(4),
+        time_control: TimeControl::None,
+        byo_yomi: None,
+    }).unwrap();
+
+    let summary = timeout(Duration::from_secs(10), coordinator.run())
+        .await
+        .unwrap()
+        .unwrap();
+
+    let result = &summary.games[0];
+
+    assert_eq!(result.winner, None);
+    assert_eq!(result.termination, Termination::Adjudication);
+    assert_eq!(result.offender, None);
+}
@@ -0,0 +1,237 @@
+//! Benchmarks of the board, compared to the cell grid it replaced.
+//!
+//! The baseline is the engine's original board: a `Vec<Vec<CellStatus>>`
+//! whose wins are checked with four `check_row` futures raced in a
+//! `tokio::select!`, each scanning the ten cells around the played move. It
+//! always looks for five in a row, so the bitboard runs with the default
+//! rules.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use gomokurs_game_engine::domain::game_engine::models::{Board, CellStatus, CheckRowAxis, PlayerColor, Position, Rules};
+use tokio::runtime::Runtime;
+
+/// The board the engine used before bitboards.
+struct GridBoard
+{
+    size: Position,
+    cells: Vec<Vec<CellStatus>>,
+}
+
+impl GridBoard
+{
+    fn new(
+        size: Position,
+    ) -> Self
+    {
+        Self {
+            size,
+            cells: vec![vec![CellStatus::Available; size.y as usize]; size.x as usize],
+        }
+    }
+
+    fn set_cell(
+        &mut self,
+        position: Position,
+        status: CellStatus,
+    )
+    {
+        self.cells[position.x as usize][position.y as usize] = status;
+    }
+
+    /// Checks if a row contains at least five consecutive cells of the same
+    /// status, scanning the ten cells around the origin.
+    async fn check_row(
+        &self,
+        origin: Position,
+        axis: CheckRowAxis,
+    ) -> bool
+    {
+        let status = self.cells[origin.x as usize][origin.y as usize];
+        let mut nb_consecutive = 0u8;
+
+        for i in -5..5 {
+            let axis_vec = axis.value();
+            let pos = Position {
+                x: (origin.x as i32 + (axis_vec.0 * i) as i32) as u8,
+                y: (origin.y as i32 + (axis_vec.1 * i) as i32) as u8,
+            };
+
+            if pos.x >= self.size.x || pos.y >= self.size.y {
+                continue;
+            } else if self.cells[pos.x as usize][pos.y as usize] == status {
+                nb_consecutive += 1;
+
+                if nb_consecutive >= 5 {
+                    return true;
+                }
+            } else {
+                nb_consecutive = 0;
+            }
+        }
+
+        false
+    }
+
+    async fn check_win(
+        &self,
+        played_move: Position,
+    ) -> bool
+    {
+        tokio::select! {
+            true = self.check_row(played_move, CheckRowAxis::Horizontal) => true,
+            true = self.check_row(played_move, CheckRowAxis::Vertical) => true,
+            true = self.check_row(played_move, CheckRowAxis::DiagonalUp) => true,
+            true = self.check_row(played_move, CheckRowAxis::DiagonalDown) => true,
+            else => false,
+        }
+    }
+}
+
+/// Lists every cell of a board in a reproducible pseudo-random order.
+fn shuffled_cells(
+    size: Position,
+    seed: u64,
+) -> Vec<Position>
+{
+    let mut cells: Vec<Position> = (0..size.x)
+        .flat_map(|x| (0..size.y).map(move |y| Position::new(x, y)))
+        .collect();
+    let mut state = seed;
+
+    for i in (1..cells.len()).rev() {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        cells.swap(i, (state % (i as u64 + 1)) as usize);
+    }
+
+    cells
+}
+
+/// Plays random moves on a board until a player wins or the board is full.
+fn playout(
+    size: Position,
+    moves: &[Position],
+    rules: &Rules,
+) -> usize
+{
+    let mut board = Board::new(size).unwrap();
+    let mut color = PlayerColor::Black;
+
+    for (turn, position) in moves.iter().enumerate() {
+        board.set_cell(*position, color.into()).unwrap();
+
+        if board.check_win(*position, rules) {
+            return turn;
+        }
+        color.switch();
+    }
+
+    moves.len()
+}
+
+/// Plays random moves on the baseline board until a player wins or the board
+/// is full.
+async fn grid_playout(
+    size: Position,
+    moves: &[Position],
+) -> usize
+{
+    let mut board = GridBoard::new(size);
+    let mut color = PlayerColor::Black;
+
+    for (turn, position) in moves.iter().enumerate() {
+        board.set_cell(*position, color.into());
+
+        if board.check_win(*position).await {
+            return turn;
+        }
+        color.switch();
+    }
+
+    moves.len()
+}
+
+fn bench_check_win(c: &mut Criterion)
+{
+    let size = Position::new(20, 20);
+    let rules = Rules::default();
+    let moves = shuffled_cells(size, 0x5eed);
+    let runtime = Runtime::new().unwrap();
+
+    // A crowded board with no winner, where every check scans long rows.
+    let mut board = Board::new(size).unwrap();
+    let mut grid = GridBoard::new(size);
+    let mut played = Vec::new();
+    let mut color = PlayerColor::Black;
+
+    for position in moves {
+        board.set_cell(position, color.into()).unwrap();
+        if board.check_win(position, &rules) {
+            board.clear_cell(position).unwrap();
+            continue;
+        }
+        grid.set_cell(position, color.into());
+        played.push(position);
+        color.switch();
+    }
+
+    let mut group = c.benchmark_group("check_win");
+
+    group.bench_function("bitboard", |b| {
+        b.iter(|| {
+            played.iter().filter(|position| board.check_win(black_box(**position), &rules)).count()
+        })
+    });
+    group.bench_function("grid", |b| {
+        b.iter(|| {
+            runtime.block_on(async {
+                let mut wins = 0;
+
+                for position in &played {
+                    if grid.check_win(black_box(*position)).await {
+                        wins += 1;
+                    }
+                }
+
+                wins
+            })
+        })
+    });
+    group.finish();
+}
+
+fn bench_playout(c: &mut Criterion)
+{
+    let rules = Rules::default();
+    let runtime = Runtime::new().unwrap();
+    let mut group = c.benchmark_group("playout");
+
+    for side in [15u8, 20, 64] {
+        let size = Position::new(side, side);
+        let games: Vec<Vec<Position>> = (1..=16).map(|seed| shuffled_cells(size, seed)).collect();
+
+        group.bench_with_input(BenchmarkId::new("bitboard", side), &games, |b, games| {
+            b.iter(|| {
+                games.iter().map(|moves| playout(size, moves, &rules)).sum::<usize>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("grid", side), &games, |b, games| {
+            b.iter(|| {
+                runtime.block_on(async {
+                    let mut turns = 0;
+
+                    for moves in games {
+                        turns += grid_playout(size, moves).await;
+                    }
+
+                    turns
+                })
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_check_win, bench_playout);
+criterion_main!(benches);
@@ -1,6 +1,10 @@
 pub mod models;
+#[cfg(feature = "runtime")]
 pub mod ports;
+#[cfg(feature = "runtime")]
 pub mod service;
 
-pub use service::Service as GameEngine;
+#[cfg(feature = "runtime")]
+pub use service::{CreateGameEngineConfiguration, Service as GameEngine};
+#[cfg(feature = "runtime")]
 pub use ports::GameEngineService;
@@ -1,9 +1,25 @@
 //! Define the models of the game manager service.
 
+#[cfg(feature = "runtime")]
+pub mod clock;
+#[cfg(feature = "runtime")]
 pub mod timer;
 pub mod state;
+pub mod rules;
+pub mod renju;
+pub mod game;
+pub mod zobrist;
+pub mod symmetry;
+pub mod record;
 pub mod error;
 
+#[cfg(feature = "runtime")]
+pub use clock::*;
+#[cfg(feature = "runtime")]
 pub use timer::*;
 pub use state::*;
+pub use rules::*;
+pub use game::*;
+pub use symmetry::*;
+pub use record::*;
 pub use error::*;
@@ -0,0 +1,104 @@
+//! Clocks measuring the time of the timers.
+//!
+//! Timers only read the time and wait for deadlines through the [`Clock`]
+//! trait. Games run on the [`TokioClock`] by default, which also follows the
+//! paused clock of tokio tests, while simulations and tests can use a
+//! [`VirtualClock`] that only moves when advanced.
+
+use tokio::sync::watch;
+use tokio::time::{Duration, Instant};
+use std::future::Future;
+use std::sync::Arc;
+
+/// A source of time, measured from the creation of the clock.
+pub trait Clock: std::fmt::Debug + Clone + Send + Sync + 'static {
+    /// Returns the time elapsed since the clock was created.
+    fn now(&self) -> Duration;
+
+    /// Waits until the clock reaches a point in time.
+    ///
+    /// # Arguments
+    /// * `deadline` - The time to wait for, measured from the creation of
+    ///   the clock.
+    fn sleep_until(&self, deadline: Duration) -> impl Future<Output = ()> + Send;
+}
+
+/// The clock of the tokio runtime.
+#[derive(Debug, Clone, Copy)]
+pub struct TokioClock {
+    /// The instant the clock was created.
+    origin: Instant,
+}
+
+impl TokioClock {
+    /// Creates a clock starting now.
+    pub fn new() -> Self {
+        Self {
+            origin: Instant::now(),
+        }
+    }
+}
+
+impl Default for TokioClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TokioClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+
+    fn sleep_until(&self, deadline: Duration) -> impl Future<Output = ()> + Send {
+        tokio::time::sleep_until(self.origin + deadline)
+    }
+}
+
+/// A clock that only moves when advanced, waking the tasks whose deadlines
+/// are reached. Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct VirtualClock {
+    /// The current time, watched by the sleeping tasks.
+    now: Arc<watch::Sender<Duration>>,
+}
+
+impl VirtualClock {
+    /// Creates a clock at time zero.
+    pub fn new() -> Self {
+        Self {
+            now: Arc::new(watch::channel(Duration::ZERO).0),
+        }
+    }
+
+    /// Moves the clock forward.
+    ///
+    /// # Arguments
+    /// * `duration` - The time to move the clock by.
+    pub fn advance(&self, duration: Duration) {
+        self.now.send_modify(|now| *now += duration);
+    }
+}
+
+impl Default for VirtualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for VirtualClock {
+    fn now(&self) -> Duration {
+        *self.now.borrow()
+    }
+
+    fn sleep_until(&self, deadline: Duration) -> impl Future<Output = ()> + Send {
+        let mut now = self.now.subscribe();
+
+        async move {
+            // The clock can no longer move once every handle is dropped.
+            if now.wait_for(|now| *now >= deadline).await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        }
+    }
+}
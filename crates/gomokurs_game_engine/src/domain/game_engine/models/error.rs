@@ -1,5 +1,6 @@
 use thiserror::Error;
-use crate::domain::game_engine::models::state::{PlayerColor, SetCellError};
+use crate::domain::game_engine::models::state::{BoardSizeError, PlayerColor, Position, SetCellError};
+use crate::domain::game_engine::models::rules::{ForbiddenMove, OpeningRestriction};
 
 /// Errors that may occur in the Game Manager service.
 #[derive(Debug, Error)]
@@ -7,6 +8,24 @@ pub enum Error {
     /// Attempted to play out of turn.
     #[error("it is not `{0}` turn")]
     NotPlayerTurn(PlayerColor),
+    /// Black attempted a move forbidden by the renju rules.
+    #[error("forbidden move: `{0}`")]
+    ForbiddenMove(ForbiddenMove),
+    /// Black attempted a move violating the opening restriction.
+    #[error("move `{position}` violates the `{restriction}` opening restriction")]
+    OpeningRestrictionViolation {
+        restriction: OpeningRestriction,
+        position: Position,
+    },
+    /// Attempted to play after the end of the game.
+    #[error("the game is over")]
+    GameOver,
+    /// Attempted to take back a move before any move was played.
+    #[error("no move to take back")]
+    NothingToTakeBack,
+    /// The board could not be created with the requested size.
+    #[error("board size error: `{0}`")]
+    BoardSizeError(#[from] BoardSizeError),
     /// An error occurred while setting a cell's status.
     #[error("set cell error: `{0}`")]
     SetCellError(#[from] SetCellError),
@@ -0,0 +1,208 @@
+//! Synchronous core of a Gomoku game.
+//!
+//! A `Game` applies the rules to a board without any timer or async runtime,
+//! so it can be stepped and cloned cheaply, for instance to generate
+//! self-play games. Playing a move does not allocate: the move history is
+//! sized for a full board when the game is created.
+//!
+//! It remains available when the default `runtime` feature, which brings the
+//! tokio based timers and service, is disabled.
+
+use crate::domain::game_engine::models::state::{Board, BoardSize, BoardSizeError, DrawReason, GameEnd, PlayerColor, Position, WinReason};
+use crate::domain::game_engine::models::rules::Rules;
+use crate::domain::game_engine::models::error::Error;
+
+/// A game of Gomoku, from the first move to its outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game
+{
+    board: Board,
+    rules: Rules,
+    /// Number of moves after which the game is declared a draw, if any.
+    max_moves: Option<usize>,
+    /// The positions played, starting with black's first move.
+    moves: Vec<Position>,
+    outcome: Option<GameEnd>,
+}
+
+impl Game
+{
+    /// Creates a new game on an empty board.
+    ///
+    /// # Arguments
+    ///
+    /// * `board_size` - The size of the board.
+    /// * `rules` - The rules the game is played under.
+    /// * `max_moves` - The number of moves after which the game is declared
+    ///   a draw, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the board cannot have the given size.
+    pub fn new(
+        board_size: BoardSize,
+        rules: Rules,
+        max_moves: Option<usize>,
+    ) -> Result<Self, BoardSizeError>
+    {
+        Ok(Self {
+            board: Board::new(board_size)?,
+            rules,
+            max_moves,
+            moves: Vec::with_capacity(board_size.x as usize * board_size.y as usize),
+            outcome: None,
+        })
+    }
+
+    /// Returns the board of the game.
+    pub fn board(
+        &self,
+    ) -> &Board
+    {
+        &self.board
+    }
+
+    /// Returns the rules the game is played under.
+    pub fn rules(
+        &self,
+    ) -> Rules
+    {
+        self.rules
+    }
+
+    /// Returns the positions played, starting with black's first move.
+    pub fn moves(
+        &self,
+    ) -> &[Position]
+    {
+        &self.moves
+    }
+
+    /// Returns the color of the next stone to be played.
+    pub fn turn_player(
+        &self,
+    ) -> PlayerColor
+    {
+        if self.moves.len().is_multiple_of(2) {
+            PlayerColor::Black
+        } else {
+            PlayerColor::White
+        }
+    }
+
+    /// Returns how the game ended, or `None` if it is still going on.
+    pub fn outcome(
+        &self,
+    ) -> Option<GameEnd>
+    {
+        self.outcome
+    }
+
+    /// Checks whether the turn player may play at a position.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the move to check.
+    pub fn check_move(
+        &self,
+        position: Position,
+    ) -> Result<(), Error>
+    {
+        if self.outcome.is_some() {
+            return Err(Error::GameOver);
+        }
+
+        let color = self.turn_player();
+        let restriction = self.rules.opening_restriction;
+
+        if color == PlayerColor::Black
+            && !restriction.allows(self.board.size, self.moves.len().div_ceil(2), position)
+        {
+            return Err(Error::OpeningRestrictionViolation { restriction, position });
+        }
+
+        self.board.check_cell_available(position)?;
+
+        if self.rules.has_forbidden_moves(color) {
+            if let Some(forbidden_move) = self.board.forbidden_move(position, self.rules.win_length) {
+                return Err(Error::ForbiddenMove(forbidden_move));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Iterates over the positions the turn player may play.
+    pub fn legal_moves(
+        &self,
+    ) -> impl Iterator<Item = Position> + '_
+    {
+        let size = self.board.size;
+
+        (0..size.x)
+            .flat_map(move |x| (0..size.y).map(move |y| Position::new(x, y)))
+            .filter(|position| self.check_move(*position).is_ok())
+    }
+
+    /// Plays a stone of the turn player.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the move.
+    ///
+    /// # Returns
+    /// How the game ended if the move ended it.
+    pub fn play(
+        &mut self,
+        position: Position,
+    ) -> Result<Option<GameEnd>, Error>
+    {
+        self.check_move(position)?;
+
+        let color = self.turn_player();
+
+        self.board.set_cell(position, color.into())?;
+        self.moves.push(position);
+
+        let nb_moves = self.moves.len();
+        let nb_cells = self.board.size.x as usize * self.board.size.y as usize;
+
+        self.outcome = if let Some(line) = self.board.winning_line(position, &self.rules) {
+            Some(GameEnd::Win(color, WinReason::Line(line)))
+        } else if nb_moves >= nb_cells {
+            Some(GameEnd::Draw(DrawReason::FullBoard))
+        } else if self.max_moves.is_some_and(|max_moves| nb_moves >= max_moves) {
+            Some(GameEnd::Draw(DrawReason::MoveLimit))
+        } else {
+            None
+        };
+
+        Ok(self.outcome)
+    }
+
+    /// Takes back the last move.
+    ///
+    /// # Returns
+    /// The color and position of the move taken back.
+    pub fn undo(
+        &mut self,
+    ) -> Result<(PlayerColor, Position), Error>
+    {
+        let position = self.moves.pop().ok_or(Error::NothingToTakeBack)?;
+
+        self.board.clear_cell(position)?;
+        self.outcome = None;
+
+        Ok((self.turn_player(), position))
+    }
+
+    /// Clears the board to start a new game under the same rules.
+    pub fn reset(
+        &mut self,
+    )
+    {
+        self.board.clear();
+        self.moves.clear();
+        self.outcome = None;
+    }
+}
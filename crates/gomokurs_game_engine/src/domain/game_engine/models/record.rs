@@ -0,0 +1,57 @@
+//! Models of the record of a game.
+
+use std::time::{Duration, SystemTime};
+use crate::domain::game_engine::models::state::{BoardSize, PlayerColor, Position};
+use crate::domain::game_engine::models::rules::Rules;
+
+/// A move kept in the record of a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedMove {
+    /// The color of the stone played.
+    pub color: PlayerColor,
+    /// The position of the stone played.
+    pub position: Position,
+    /// The wall-clock time at which the move was registered.
+    pub timestamp: SystemTime,
+    /// The time the player spent on the move.
+    pub time_used: Duration,
+    /// The match time left to the player after the move.
+    pub time_remaining: Duration,
+}
+
+/// The record of a game, holding every move in the order they were played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRecord {
+    /// The size of the board the game is played on.
+    pub board_size: BoardSize,
+    /// The rules the game is played under.
+    pub rules: Rules,
+    /// The moves played, starting with black's first move.
+    pub moves: Vec<RecordedMove>,
+}
+
+impl GameRecord {
+    /// Creates an empty record for a game.
+    pub fn new(
+        board_size: BoardSize,
+        rules: Rules,
+    ) -> Self
+    {
+        Self {
+            board_size,
+            rules,
+            moves: Vec::new(),
+        }
+    }
+
+    /// Returns the color of the next stone to be played.
+    pub fn next_color(
+        &self,
+    ) -> PlayerColor
+    {
+        match self.moves.last() {
+            Some(last_move) => last_move.color.other(),
+            None => PlayerColor::Black,
+        }
+    }
+}
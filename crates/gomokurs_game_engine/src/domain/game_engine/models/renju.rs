@@ -0,0 +1,276 @@
+//! Detection of the moves forbidden to black under renju rules.
+//!
+//! The analysis works on hypothetical black stones layered over the board so
+//! that the board itself is never modified while looking ahead. Patterns are
+//! named after the standard win length: a "five" is a row of exactly the win
+//! length, a "four" is one stone short of it, and so on.
+
+use crate::domain::game_engine::models::state::{Board, CellStatus, CheckRowAxis, Position};
+use crate::domain::game_engine::models::rules::ForbiddenMove;
+
+/// How many nested moves are examined when checking whether a three can
+/// legally become a straight four.
+const MAX_LOOKAHEAD_DEPTH: u8 = 3;
+
+/// The most hypothetical stones layered at once: one per nested move, plus
+/// the two tried while looking for a straight four at the deepest level.
+const MAX_STONES: usize = MAX_LOOKAHEAD_DEPTH as usize + 3;
+
+/// The empty cells that would turn a row into a five, as offsets along an
+/// axis.
+#[derive(Debug, Clone, Copy, Default)]
+struct FivePoints {
+    count: usize,
+    first: i32,
+    last: i32,
+}
+
+impl FivePoints {
+    /// Checks whether the points are both ends of a straight four.
+    fn are_straight_four_ends(
+        &self,
+        win_length: u8,
+    ) -> bool
+    {
+        self.count == 2 && self.last - self.first == win_length as i32
+    }
+}
+
+/// A board with additional hypothetical black stones.
+struct Lookahead<'a> {
+    board: &'a Board,
+    win_length: u8,
+    stones: [(i32, i32); MAX_STONES],
+    nb_stones: usize,
+}
+
+impl Lookahead<'_> {
+    /// Layers a hypothetical black stone over the board.
+    fn push(
+        &mut self,
+        cell: (i32, i32),
+    )
+    {
+        self.stones[self.nb_stones] = cell;
+        self.nb_stones += 1;
+    }
+
+    /// Removes the last hypothetical black stone.
+    fn pop(
+        &mut self,
+    )
+    {
+        self.nb_stones -= 1;
+    }
+
+    /// Returns the status of a cell, accounting for hypothetical stones.
+    fn cell_at(
+        &self,
+        x: i32,
+        y: i32,
+    ) -> Option<CellStatus>
+    {
+        if self.stones[..self.nb_stones].contains(&(x, y)) {
+            self.board.cell_at(x, y).map(|_| CellStatus::Black)
+        } else {
+            self.board.cell_at(x, y)
+        }
+    }
+
+    /// Counts consecutive black stones through `origin` along an axis.
+    fn row_length(
+        &self,
+        origin: (i32, i32),
+        axis: CheckRowAxis,
+    ) -> u8
+    {
+        let (dx, dy) = axis.value();
+        let mut length = 1u8;
+
+        for direction in [1i32, -1i32] {
+            let mut x = origin.0 + dx as i32 * direction;
+            let mut y = origin.1 + dy as i32 * direction;
+
+            while self.cell_at(x, y) == Some(CellStatus::Black) {
+                length = length.saturating_add(1);
+                x += dx as i32 * direction;
+                y += dy as i32 * direction;
+            }
+        }
+
+        length
+    }
+
+    /// Finds the empty cells along an axis that would turn the row through
+    /// `origin` into a five.
+    fn five_points(
+        &mut self,
+        origin: (i32, i32),
+        axis: CheckRowAxis,
+    ) -> FivePoints
+    {
+        let (dx, dy) = axis.value();
+        let reach = self.win_length as i32 - 1;
+        let mut points = FivePoints::default();
+
+        for offset in -reach..=reach {
+            let cell = (origin.0 + dx as i32 * offset, origin.1 + dy as i32 * offset);
+
+            if offset == 0 || self.cell_at(cell.0, cell.1) != Some(CellStatus::Available) {
+                continue;
+            }
+
+            self.push(cell);
+            let is_five = self.row_length(origin, axis) == self.win_length;
+            self.pop();
+
+            if is_five {
+                if points.count == 0 {
+                    points.first = offset;
+                }
+                points.last = offset;
+                points.count += 1;
+            }
+        }
+
+        points
+    }
+
+    /// Counts the fours going through `origin` along an axis.
+    fn fours(
+        &mut self,
+        origin: (i32, i32),
+        axis: CheckRowAxis,
+    ) -> usize
+    {
+        let points = self.five_points(origin, axis);
+
+        // Both ends of a straight four complete the same four.
+        if points.are_straight_four_ends(self.win_length) {
+            1
+        } else {
+            points.count
+        }
+    }
+
+    /// Checks whether the row through `origin` along an axis is a straight
+    /// four, a four that can be completed at both of its ends.
+    fn is_straight_four(
+        &mut self,
+        origin: (i32, i32),
+        axis: CheckRowAxis,
+    ) -> bool
+    {
+        self.five_points(origin, axis).are_straight_four_ends(self.win_length)
+    }
+
+    /// Checks whether the row through `origin` along an axis is an open
+    /// three, a three that can become a straight four with a legal move.
+    fn is_open_three(
+        &mut self,
+        origin: (i32, i32),
+        axis: CheckRowAxis,
+        depth: u8,
+    ) -> bool
+    {
+        let (dx, dy) = axis.value();
+        let reach = self.win_length as i32 - 1;
+
+        (-reach..=reach).any(|offset| {
+            let cell = (origin.0 + dx as i32 * offset, origin.1 + dy as i32 * offset);
+
+            if offset == 0 || self.cell_at(cell.0, cell.1) != Some(CellStatus::Available) {
+                return false;
+            }
+
+            self.push(cell);
+            let is_straight_four = self.is_straight_four(origin, axis);
+            self.pop();
+
+            is_straight_four && self.forbidden_move(cell, depth + 1).is_none()
+        })
+    }
+
+    /// Returns the forbidden pattern created by a black stone at `position`.
+    fn forbidden_move(
+        &mut self,
+        position: (i32, i32),
+        depth: u8,
+    ) -> Option<ForbiddenMove>
+    {
+        if depth > MAX_LOOKAHEAD_DEPTH {
+            return None;
+        }
+
+        self.push(position);
+        let forbidden_move = self.analyze(position, depth);
+        self.pop();
+
+        forbidden_move
+    }
+
+    /// Looks for forbidden patterns around a black stone already layered
+    /// over the board.
+    fn analyze(
+        &mut self,
+        position: (i32, i32),
+        depth: u8,
+    ) -> Option<ForbiddenMove>
+    {
+        let lengths = CheckRowAxis::ALL.map(|axis| self.row_length(position, axis));
+
+        // Making a five wins the game, even if other patterns appear.
+        if lengths.contains(&self.win_length) {
+            return None;
+        } else if lengths.iter().any(|&length| length > self.win_length) {
+            return Some(ForbiddenMove::Overline);
+        }
+
+        let mut fours = 0;
+        let mut threes = 0;
+
+        for axis in CheckRowAxis::ALL {
+            let axis_fours = self.fours(position, axis);
+
+            if axis_fours > 0 {
+                fours += axis_fours;
+            } else if self.is_open_three(position, axis, depth) {
+                threes += 1;
+            }
+        }
+
+        if fours >= 2 {
+            Some(ForbiddenMove::DoubleFour)
+        } else if threes >= 2 {
+            Some(ForbiddenMove::DoubleThree)
+        } else {
+            None
+        }
+    }
+}
+
+impl Board
+{
+    /// Returns the renju pattern black would create by playing at a
+    /// position, or `None` if the move is allowed.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the available cell black would play.
+    /// * `win_length` - The number of stones in a row needed to win.
+    pub fn forbidden_move(
+        &self,
+        position: Position,
+        win_length: u8,
+    ) -> Option<ForbiddenMove>
+    {
+        let mut lookahead = Lookahead {
+            board: self,
+            win_length,
+            stones: [(0, 0); MAX_STONES],
+            nb_stones: 0,
+        };
+
+        lookahead.forbidden_move((position.x as i32, position.y as i32), 0)
+    }
+}
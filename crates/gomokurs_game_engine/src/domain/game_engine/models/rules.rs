@@ -0,0 +1,269 @@
+//! Rules arbitrated by the game engine.
+//!
+//! This module defines which rule set a game is played under and the
+//! forbidden patterns black may not create under renju rules.
+
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+use crate::domain::game_engine::models::state::{BoardSize, PlayerColor, Position, Row};
+
+/// The family of rules used to decide what a winning row is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RuleSet {
+    /// Any row of `win_length` or more stones wins, for both colors.
+    #[default]
+    Freestyle,
+    /// Black may only win with exactly `win_length` stones and may not play
+    /// double-three, double-four or overline moves. White wins with
+    /// `win_length` or more stones.
+    Renju,
+}
+
+impl fmt::Display for RuleSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            RuleSet::Freestyle => write!(f, "freestyle"),
+            RuleSet::Renju => write!(f, "renju"),
+        }
+    }
+}
+
+impl FromStr for RuleSet {
+    type Err = ParseRulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "freestyle" => Ok(RuleSet::Freestyle),
+            "renju" => Ok(RuleSet::Renju),
+            _ => Err(ParseRulesError::UnknownRuleSet(s.to_string())),
+        }
+    }
+}
+
+/// Defines how the game engine reacts to a forbidden move played by black.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ForbiddenMovePolicy {
+    /// The move is rejected and black has to play another one, whatever
+    /// the coordinator does with illegal moves.
+    #[default]
+    Reject,
+    /// The move is rejected and black loses the game.
+    Loss,
+}
+
+impl fmt::Display for ForbiddenMovePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            ForbiddenMovePolicy::Reject => write!(f, "reject"),
+            ForbiddenMovePolicy::Loss => write!(f, "loss"),
+        }
+    }
+}
+
+impl FromStr for ForbiddenMovePolicy {
+    type Err = ParseRulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "reject" => Ok(ForbiddenMovePolicy::Reject),
+            "loss" => Ok(ForbiddenMovePolicy::Loss),
+            _ => Err(ParseRulesError::UnknownForbiddenMovePolicy(s.to_string())),
+        }
+    }
+}
+
+/// Restrictions on where black may place its first stones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OpeningRestriction {
+    /// Black may place its first stones anywhere.
+    #[default]
+    None,
+    /// Black's first stone goes in the center and its second stone at least
+    /// 3 intersections away from the center.
+    Pro,
+    /// Black's first stone goes in the center and its second stone at least
+    /// 4 intersections away from the center.
+    LongPro,
+}
+
+impl OpeningRestriction {
+    /// Returns the minimum distance from the center of black's second stone.
+    pub fn min_distance(
+        &self,
+    ) -> u8
+    {
+        match &self {
+            OpeningRestriction::None => 0,
+            OpeningRestriction::Pro => 3,
+            OpeningRestriction::LongPro => 4,
+        }
+    }
+
+    /// Checks whether black may place its stone at a position.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the board.
+    /// * `nb_black_stones` - The number of stones black already placed.
+    /// * `position` - The position of the stone to place.
+    pub fn allows(
+        &self,
+        size: BoardSize,
+        nb_black_stones: usize,
+        position: Position,
+    ) -> bool
+    {
+        let center = Position::new(size.x / 2, size.y / 2);
+        let distance = position.x.abs_diff(center.x).max(position.y.abs_diff(center.y));
+
+        match (self, nb_black_stones) {
+            (OpeningRestriction::None, _) => true,
+            (_, 0) => position == center,
+            (_, 1) => distance >= self.min_distance(),
+            _ => true,
+        }
+    }
+}
+
+impl fmt::Display for OpeningRestriction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            OpeningRestriction::None => write!(f, "none"),
+            OpeningRestriction::Pro => write!(f, "pro"),
+            OpeningRestriction::LongPro => write!(f, "long-pro"),
+        }
+    }
+}
+
+impl FromStr for OpeningRestriction {
+    type Err = ParseRulesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(OpeningRestriction::None),
+            "pro" => Ok(OpeningRestriction::Pro),
+            "long-pro" => Ok(OpeningRestriction::LongPro),
+            _ => Err(ParseRulesError::UnknownOpeningRestriction(s.to_string())),
+        }
+    }
+}
+
+/// A pattern black is not allowed to create under renju rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForbiddenMove {
+    /// The move creates two open threes at once.
+    DoubleThree,
+    /// The move creates two fours at once.
+    DoubleFour,
+    /// The move creates a row longer than the win length.
+    Overline,
+}
+
+impl fmt::Display for ForbiddenMove {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            ForbiddenMove::DoubleThree => write!(f, "double-three"),
+            ForbiddenMove::DoubleFour => write!(f, "double-four"),
+            ForbiddenMove::Overline => write!(f, "overline"),
+        }
+    }
+}
+
+/// The number of stones in a row needed to win a standard game of gomoku.
+pub const DEFAULT_WIN_LENGTH: u8 = 5;
+
+/// The rules a game is played under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+    /// The rule set deciding what a winning row is.
+    pub rule_set: RuleSet,
+    /// How forbidden moves are sanctioned when playing renju.
+    pub forbidden_move_policy: ForbiddenMovePolicy,
+    /// Number of stones in a row needed to win, making the game an
+    /// m,n,k-game.
+    pub win_length: u8,
+    /// Whether only rows of exactly `win_length` stones win, overlines being
+    /// ignored for both colors.
+    pub exact_length: bool,
+    /// Whether a row capped by opponent stones at both ends does not win.
+    pub caro: bool,
+    /// Restrictions on the placement of black's first stones.
+    pub opening_restriction: OpeningRestriction,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self {
+            rule_set: RuleSet::default(),
+            forbidden_move_policy: ForbiddenMovePolicy::default(),
+            win_length: DEFAULT_WIN_LENGTH,
+            exact_length: false,
+            caro: false,
+            opening_restriction: OpeningRestriction::default(),
+        }
+    }
+}
+
+impl Rules {
+    /// Checks whether a row of stones wins the game for `color`.
+    pub fn is_winning_row(
+        &self,
+        color: PlayerColor,
+        row: &Row,
+    ) -> bool
+    {
+        let is_long_enough = match (self.rule_set, color) {
+            _ if self.exact_length => row.length == self.win_length,
+            (RuleSet::Renju, PlayerColor::Black) => row.length == self.win_length,
+            _ => row.length >= self.win_length,
+        };
+
+        is_long_enough && !(self.caro && row.is_blocked_by(color.other().into()))
+    }
+
+    /// Returns the rules as the bitmask sent to players through the
+    /// Gomocup `INFO rule` command.
+    pub fn as_gomocup_rule(
+        &self,
+    ) -> u8
+    {
+        let mut rule = 0;
+
+        if self.exact_length {
+            rule |= 1;
+        }
+        if self.rule_set == RuleSet::Renju {
+            rule |= 4;
+        }
+        if self.caro {
+            rule |= 8;
+        }
+
+        rule
+    }
+
+    /// Checks whether `color` has to be checked for forbidden moves.
+    pub fn has_forbidden_moves(
+        &self,
+        color: PlayerColor,
+    ) -> bool
+    {
+        self.rule_set == RuleSet::Renju && color == PlayerColor::Black
+    }
+}
+
+/// Errors that may occur while parsing rules from their textual
+/// representation.
+#[derive(Debug, Error)]
+pub enum ParseRulesError {
+    /// The rule set is not supported by the game engine.
+    #[error("unknown rule set `{0}`")]
+    UnknownRuleSet(String),
+    /// The forbidden move policy is not supported by the game engine.
+    #[error("unknown forbidden move policy `{0}`")]
+    UnknownForbiddenMovePolicy(String),
+    /// The opening restriction is not supported by the game engine.
+    #[error("unknown opening restriction `{0}`")]
+    UnknownOpeningRestriction(String),
+}
@@ -3,6 +3,8 @@
 use std::fmt;
 use std::hash::Hash;
 use thiserror::Error;
+use crate::domain::game_engine::models::rules::{ForbiddenMove, Rules};
+use crate::domain::game_engine::models::zobrist;
 
 /// Represents a player's color in the game: either black or white.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -32,11 +34,11 @@ impl PlayerColor
     }
 }
 
-impl Into<CellStatus> for PlayerColor
+impl From<PlayerColor> for CellStatus
 {
-    fn into(self) -> CellStatus
+    fn from(val: PlayerColor) -> Self
     {
-        match self {
+        match val {
             PlayerColor::Black => CellStatus::Black,
             PlayerColor::White => CellStatus::White,
         }
@@ -64,8 +66,8 @@ impl Position {
     /// Creates a new `Position` with the given coordinates.
     pub fn new(x: u8, y: u8) -> Self {
         Self {
-            x: x,
-            y: y
+            x,
+            y
         }
     }
 }
@@ -89,6 +91,7 @@ pub enum CellStatus
 }
 
 /// Defines the direction of a row for win checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CheckRowAxis
 {
     /// Horizontal row (left-to-right).
@@ -103,6 +106,14 @@ pub enum CheckRowAxis
 
 impl CheckRowAxis
 {
+    /// Every direction a row can follow on the board.
+    pub const ALL: [CheckRowAxis; 4] = [
+        CheckRowAxis::Horizontal,
+        CheckRowAxis::Vertical,
+        CheckRowAxis::DiagonalUp,
+        CheckRowAxis::DiagonalDown,
+    ];
+
     pub const fn value(&self) -> (i8, i8)
     {
         match *self {
@@ -114,14 +125,93 @@ impl CheckRowAxis
     }
 }
 
+/// A row of consecutive cells sharing the same status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row
+{
+    /// Number of cells in the row.
+    pub length: u8,
+    /// Status of the cell beyond each end of the row, `None` when the row
+    /// reaches the edge of the board.
+    pub ends: [Option<CellStatus>; 2],
+}
+
+impl Row
+{
+    /// Checks whether both ends of the row are capped by cells of the given
+    /// status.
+    pub fn is_blocked_by(
+        &self,
+        status: CellStatus,
+    ) -> bool
+    {
+        self.ends.iter().all(|end| *end == Some(status))
+    }
+}
+
+/// The largest width or height of a board, each line of cells being packed
+/// in a 64 bits word.
+pub const MAX_BOARD_SIZE: u8 = 64;
+
+/// The stones of one color, packed as one bitmask per line of the board along
+/// each axis.
+///
+/// Bit `i` of a line is set when its `i`-th cell holds a stone, cells being
+/// indexed by their `x` coordinate, or by their `y` coordinate for vertical
+/// lines. Following an axis direction always moves towards higher bits, so
+/// the row through a stone is read with a single bit scan on each side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bitboard
+{
+    /// The lines of every axis, one axis after the other in the declaration
+    /// order of `CheckRowAxis`.
+    lines: Vec<u64>,
+}
+
+impl Bitboard
+{
+    /// Creates an empty bitboard for a board of the specified size.
+    fn new(
+        size: BoardSize,
+    ) -> Self
+    {
+        let (width, height) = (size.x as usize, size.y as usize);
+
+        Self {
+            lines: vec![0; height + width + 2 * (width + height - 1)],
+        }
+    }
+
+    /// Checks whether a cell, located by its line and bit, holds a stone.
+    fn contains(
+        &self,
+        (line, bit): (usize, u32),
+    ) -> bool
+    {
+        self.lines[line] >> bit & 1 == 1
+    }
+}
+
 /// Represents the game board in a Gomoku match.
-#[derive(Debug, Clone)]
+///
+/// Stones are stored as bitboards, which makes measuring a row a constant
+/// time operation. The status of each cell is exposed through `cell` and
+/// the `cells` grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Board
 {
     /// Dimensions of the board.
     pub size: BoardSize,
-    /// Current state of each cell on the board.
+    /// Current state of each cell on the board, indexed by column then by
+    /// row. It mirrors the bitboards, and is only meant to be changed
+    /// through `set_cell`, `clear_cell` and `clear`.
     pub cells: Vec<Vec<CellStatus>>,
+    /// Stones of the black player.
+    black: Bitboard,
+    /// Stones of the white player.
+    white: Bitboard,
+    /// Zobrist key of the position, updated with each stone.
+    key: u64,
 }
 
 /// Errors related to board operations.
@@ -138,21 +228,148 @@ pub enum SetCellError {
     },
 }
 
+/// Errors related to the creation of a board.
+#[derive(Debug, Error)]
+pub enum BoardSizeError {
+    /// A dimension of the board is zero or greater than `MAX_BOARD_SIZE`.
+    #[error("board size `{0}` must be between 1 and {MAX_BOARD_SIZE} on each side")]
+    OutOfRange(BoardSize),
+}
+
 /// The size of the board as a 2D dimension.
 pub type BoardSize = Position;
 
 impl Board
 {
     /// Creates a new empty board of the specified size.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a dimension is zero or greater than
+    /// `MAX_BOARD_SIZE`.
     pub fn new(
         size: BoardSize,
-    ) -> Self
+    ) -> Result<Self, BoardSizeError>
     {
-        Self {
-            size: size,
-            cells: (0..size.x)
-                .map(|_| vec![CellStatus::Available; size.y as usize])
-                .collect::<Vec<Vec<CellStatus>>>(),
+        if !(1..=MAX_BOARD_SIZE).contains(&size.x) || !(1..=MAX_BOARD_SIZE).contains(&size.y) {
+            return Err(BoardSizeError::OutOfRange(size));
+        }
+
+        Ok(Self {
+            size,
+            cells: vec![vec![CellStatus::Available; size.y as usize]; size.x as usize],
+            black: Bitboard::new(size),
+            white: Bitboard::new(size),
+            key: zobrist::empty_board_key(size),
+        })
+    }
+
+    /// Returns the line going through a cell along an axis, and the bit of
+    /// the cell in this line.
+    fn locate(
+        &self,
+        axis: CheckRowAxis,
+        x: u8,
+        y: u8,
+    ) -> (usize, u32)
+    {
+        let (width, height) = (self.size.x as usize, self.size.y as usize);
+        let (x, y) = (x as usize, y as usize);
+
+        match axis {
+            CheckRowAxis::Horizontal => (y, x as u32),
+            CheckRowAxis::Vertical => (height + x, y as u32),
+            CheckRowAxis::DiagonalUp => (height + width + x + y, x as u32),
+            CheckRowAxis::DiagonalDown => {
+                (2 * (height + width) - 1 + x + height - 1 - y, x as u32)
+            },
+        }
+    }
+
+    /// Returns the mask of the bits that are on the board, in the line going
+    /// through a cell along an axis.
+    fn line_span(
+        &self,
+        axis: CheckRowAxis,
+        x: u8,
+        y: u8,
+    ) -> u64
+    {
+        let (width, height) = (self.size.x as usize, self.size.y as usize);
+        let diagonal = match axis {
+            CheckRowAxis::Horizontal | CheckRowAxis::Vertical => None,
+            CheckRowAxis::DiagonalUp => Some(x as usize + y as usize),
+            CheckRowAxis::DiagonalDown => Some(x as usize + height - 1 - y as usize),
+        };
+        let (first, last) = match (axis, diagonal) {
+            (_, Some(diagonal)) => (diagonal.saturating_sub(height - 1), diagonal.min(width - 1)),
+            (CheckRowAxis::Vertical, None) => (0, height - 1),
+            (_, None) => (0, width - 1),
+        };
+
+        (u64::MAX >> (63 - last)) & (u64::MAX << first)
+    }
+
+    /// Removes every stone from the board.
+    pub fn clear(
+        &mut self,
+    )
+    {
+        for column in &mut self.cells {
+            column.fill(CellStatus::Available);
+        }
+        self.black.lines.fill(0);
+        self.white.lines.fill(0);
+        self.key = zobrist::empty_board_key(self.size);
+    }
+
+    /// Returns the Zobrist key of the position, identifying the stones on
+    /// the board and its size.
+    pub fn key(
+        &self,
+    ) -> u64
+    {
+        self.key
+    }
+
+    /// Returns the horizontal lines of the black and white stones, which
+    /// describe the whole position.
+    pub(crate) fn rows(
+        &self,
+    ) -> (&[u64], &[u64])
+    {
+        let height = self.size.y as usize;
+
+        (&self.black.lines[..height], &self.white.lines[..height])
+    }
+
+    /// Returns the status of a cell, or `None` if it is outside the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position of the board's cell to read.
+    pub fn cell(
+        &self,
+        position: Position,
+    ) -> Option<CellStatus>
+    {
+        self.cell_at(position.x as i32, position.y as i32)
+    }
+
+    /// Checks that a cell exists and is available for a move.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `position` - The position of the board's cell to check.
+    pub fn check_cell_available(
+        &self,
+        position: Position,
+    ) -> Result<(), SetCellError>
+    {
+        match self.cell(position) {
+            None => Err(SetCellError::OutOfBounds{position, size: self.size}),
+            Some(CellStatus::Available) => Ok(()),
+            Some(_) => Err(SetCellError::UnavailableCell(position)),
         }
     }
 
@@ -168,56 +385,130 @@ impl Board
         new_status: CellStatus,
     ) -> Result<(), SetCellError>
     {
-        if position.x > self.size.x || position.y > self.size.y {
-            return Err(SetCellError::OutOfBounds{position, size: self.size});
-        } else if self.cells[position.x as usize][position.y as usize] != CellStatus::Available {
-            return Err(SetCellError::UnavailableCell(position));
-        }
+        self.check_cell_available(position)?;
 
+        let lines = CheckRowAxis::ALL.map(|axis| self.locate(axis, position.x, position.y));
+        let (bitboard, color) = match new_status {
+            CellStatus::Available => return Ok(()),
+            CellStatus::Black => (&mut self.black, PlayerColor::Black),
+            CellStatus::White => (&mut self.white, PlayerColor::White),
+        };
+
+        for (line, bit) in lines {
+            bitboard.lines[line] |= 1 << bit;
+        }
         self.cells[position.x as usize][position.y as usize] = new_status;
+        self.key ^= zobrist::stone_key(color, position);
         
         Ok(())
     }
 
-    /// Checks if a row contains at least five consecutive cells of the same status.
+    /// Makes a cell available again, removing the stone on it.
     /// 
     /// # Arguments
     /// 
-    /// - `origin`: The starting position for the check.
-    /// - `axis`: The direction to check.
-    async fn check_row(
+    /// * `position` - The position of the board's cell to clear.
+    pub fn clear_cell(
+        &mut self,
+        position: Position,
+    ) -> Result<(), SetCellError>
+    {
+        if position.x >= self.size.x || position.y >= self.size.y {
+            return Err(SetCellError::OutOfBounds{position, size: self.size});
+        }
+
+        let color = match self.cell(position) {
+            Some(CellStatus::Black) => PlayerColor::Black,
+            Some(CellStatus::White) => PlayerColor::White,
+            _ => return Ok(()),
+        };
+        let lines = CheckRowAxis::ALL.map(|axis| self.locate(axis, position.x, position.y));
+        let bitboard = match color {
+            PlayerColor::Black => &mut self.black,
+            PlayerColor::White => &mut self.white,
+        };
+
+        for (line, bit) in lines {
+            bitboard.lines[line] &= !(1 << bit);
+        }
+        self.cells[position.x as usize][position.y as usize] = CellStatus::Available;
+        self.key ^= zobrist::stone_key(color, position);
+
+        Ok(())
+    }
+
+    /// Returns the status of the cell at signed coordinates, or `None` if
+    /// they are outside the board.
+    pub(crate) fn cell_at(
+        &self,
+        x: i32,
+        y: i32,
+    ) -> Option<CellStatus>
+    {
+        if x < 0 || y < 0 || x >= self.size.x as i32 || y >= self.size.y as i32 {
+            return None;
+        }
+
+        let cell = (y as usize, x as u32);
+
+        if self.black.contains(cell) {
+            Some(CellStatus::Black)
+        } else if self.white.contains(cell) {
+            Some(CellStatus::White)
+        } else {
+            Some(CellStatus::Available)
+        }
+    }
+
+    /// Counts the cells sharing the status of `origin` after it along an
+    /// axis, `origin` included, and before it.
+    fn run(
         &self,
         origin: Position,
         axis: CheckRowAxis,
-    ) -> bool
+    ) -> (i32, i32)
     {
-        let status = self.cells[origin.x as usize][origin.y as usize];
-        let mut nb_consecutive = 0u8;
+        let (line, bit) = self.locate(axis, origin.x, origin.y);
+        let mask = match self.cell(origin) {
+            Some(CellStatus::Black) => self.black.lines[line],
+            Some(CellStatus::White) => self.white.lines[line],
+            _ => {
+                let occupied = self.black.lines[line] | self.white.lines[line];
 
-        for i in -5..5 {
-            let axis_vec = axis.value();
-            let pos = Position {
-                x: (origin.x as i32 + (axis_vec.0 * i) as i32) as u8,
-                y: (origin.y as i32 + (axis_vec.1 * i) as i32) as u8,
-            };
+                !occupied & self.line_span(axis, origin.x, origin.y)
+            },
+        };
 
-            if pos.x >= self.size.x || pos.y >= self.size.y
-            {
-                continue;
-            } else {
-                if self.cells[pos.x as usize][pos.y as usize] == status {
-                    nb_consecutive += 1;
-
-                    if nb_consecutive >= 5 {
-                        return true;
-                    }
-                } else {
-                    nb_consecutive = 0;
-                }
-            }
-        }
+        let forward = (mask >> bit).trailing_ones();
+        let backward = mask.checked_shl(64 - bit).map_or(0, u64::leading_ones);
+
+        (forward as i32, backward as i32)
+    }
+
+    /// Measures the row of consecutive cells sharing the status of `origin`
+    /// along an axis, `origin` included.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `origin`: The position the row goes through.
+    /// - `axis`: The direction to check.
+    pub fn row(
+        &self,
+        origin: Position,
+        axis: CheckRowAxis,
+    ) -> Row
+    {
+        let (forward, backward) = self.run(origin, axis);
+        let (dx, dy) = axis.value();
+        let (x, y) = (origin.x as i32, origin.y as i32);
 
-        false
+        Row {
+            length: (forward + backward) as u8,
+            ends: [
+                self.cell_at(x + dx as i32 * forward, y + dy as i32 * forward),
+                self.cell_at(x - dx as i32 * (backward + 1), y - dy as i32 * (backward + 1)),
+            ],
+        }
     }
 
     /// Checks if a move results in a win.
@@ -225,24 +516,67 @@ impl Board
     /// # Arguments
     /// 
     /// - `played_move`: The position of the last move.
-    pub async fn check_win(
+    /// - `rules`: The rules deciding what a winning row is.
+    pub fn check_win(
         &self,
         played_move: Position,
+        rules: &Rules,
     ) -> bool
     {
-        tokio::select! {
-            true = self.check_row(
-                played_move, CheckRowAxis::Horizontal) => true,
-            true = self.check_row(
-                played_move, CheckRowAxis::Vertical) => true,
-            true = self.check_row(
-                played_move, CheckRowAxis::DiagonalUp) => true,
-            true = self.check_row(
-                played_move, CheckRowAxis::DiagonalDown) => true,
-            else => {
-                false
+        self.winning_line(played_move, rules).is_some()
+    }
+
+    /// Returns the winning row made by a move, if any.
+    /// 
+    /// # Arguments
+    /// 
+    /// - `played_move`: The position of the last move.
+    /// - `rules`: The rules deciding what a winning row is.
+    pub fn winning_line(
+        &self,
+        played_move: Position,
+        rules: &Rules,
+    ) -> Option<WinningLine>
+    {
+        let color = match self.cell(played_move) {
+            Some(CellStatus::Black) => PlayerColor::Black,
+            Some(CellStatus::White) => PlayerColor::White,
+            _ => return None,
+        };
+
+        // Only rows long enough to win are worth looking at their ends.
+        CheckRowAxis::ALL.into_iter().find_map(|axis| {
+            let (forward, backward) = self.run(played_move, axis);
+
+            if forward + backward < rules.win_length as i32
+                || !rules.is_winning_row(color, &self.row(played_move, axis))
+            {
+                return None;
             }
-        }
+
+            let (dx, dy) = axis.value();
+
+            Some(WinningLine {
+                start: Position::new(
+                    (played_move.x as i32 - dx as i32 * backward) as u8,
+                    (played_move.y as i32 - dy as i32 * backward) as u8,
+                ),
+                axis,
+                length: (forward + backward) as u8,
+            })
+        })
+    }
+}
+
+/// Boards are hashed by their Zobrist key.
+impl Hash for Board
+{
+    fn hash<H: std::hash::Hasher>(
+        &self,
+        state: &mut H,
+    )
+    {
+        state.write_u64(self.key);
     }
 }
 
@@ -275,13 +609,143 @@ impl fmt::Display for Board
     }
 }
 
+/// The row of stones that won a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WinningLine
+{
+    /// The first stone of the row.
+    pub start: Position,
+    /// The direction of the row from its first stone.
+    pub axis: CheckRowAxis,
+    /// The number of stones in the row.
+    pub length: u8,
+}
+
+impl WinningLine
+{
+    /// Iterates over the positions of the stones of the row, from its first
+    /// stone.
+    pub fn positions(
+        &self,
+    ) -> impl Iterator<Item = Position>
+    {
+        let (dx, dy) = self.axis.value();
+        let start = *self;
+
+        (0..self.length as i16).map(move |i| Position::new(
+            (start.start.x as i16 + dx as i16 * i) as u8,
+            (start.start.y as i16 + dy as i16 * i) as u8,
+        ))
+    }
+}
+
+impl fmt::Display for WinningLine
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        let positions = self.positions()
+            .map(|position| position.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        write!(f, "{}", positions)
+    }
+}
+
+/// The time limit a player exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutKind
+{
+    /// The player exceeded the duration of a turn.
+    Turn,
+    /// The player exceeded the total duration of the match.
+    Match,
+    /// The player exceeded the last byo-yomi period, numbered from one.
+    Period(u32),
+}
+
+impl fmt::Display for TimeoutKind
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match &self {
+            TimeoutKind::Turn => write!(f, "turn"),
+            TimeoutKind::Match => write!(f, "match"),
+            TimeoutKind::Period(period) => write!(f, "byo-yomi period {}", period),
+        }
+    }
+}
+
+/// The reason a player won a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WinReason
+{
+    /// The winner made a winning row.
+    Line(WinningLine),
+    /// The opponent ran out of time.
+    Timeout(TimeoutKind),
+    /// The opponent played a forbidden move at a position while such moves
+    /// lose.
+    ForbiddenMove(ForbiddenMove, Position),
+}
+
+impl fmt::Display for WinReason
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match &self {
+            WinReason::Line(line) => write!(f, "with the row {}", line),
+            WinReason::Timeout(TimeoutKind::Period(period)) => {
+                write!(f, "after the opponent ran out of byo-yomi period {}", period)
+            },
+            WinReason::Timeout(kind) => write!(f, "on {} time", kind),
+            WinReason::ForbiddenMove(forbidden_move, position) => {
+                write!(f, "after a forbidden {} of the opponent at {}", forbidden_move, position)
+            },
+        }
+    }
+}
+
+/// The reason a game ended in a draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawReason
+{
+    /// Every cell of the board is taken.
+    FullBoard,
+    /// The game reached its maximum number of moves.
+    MoveLimit,
+}
+
+impl fmt::Display for DrawReason
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter,
+    ) -> fmt::Result
+    {
+        match &self {
+            DrawReason::FullBoard => write!(f, "on a full board"),
+            DrawReason::MoveLimit => write!(f, "on the move limit"),
+        }
+    }
+}
+
 /// Represents the end state of a Gomoku game.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameEnd {
     /// A player has won the game.
-    Win(PlayerColor),
+    Win(PlayerColor, WinReason),
     /// The game ended in a draw.
-    Draw,
+    Draw(DrawReason),
 }
 
 impl fmt::Display for GameEnd
@@ -292,8 +756,8 @@ impl fmt::Display for GameEnd
     ) -> fmt::Result
     {
         match &self {
-            GameEnd::Win(p) => write!(f, "{} won", p),
-            GameEnd::Draw => write!(f, "board filled, draw"),
+            GameEnd::Win(p, reason) => write!(f, "{} won {}", p, reason),
+            GameEnd::Draw(reason) => write!(f, "draw {}", reason),
         }
     }
 }
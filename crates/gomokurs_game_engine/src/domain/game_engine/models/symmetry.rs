@@ -0,0 +1,176 @@
+//! Symmetries of the board.
+//!
+//! A square board has eight symmetries: four rotations and four reflections.
+//! Those that swap the axes turn a rectangular board into a board of
+//! transposed size, so only the four others map a rectangular board onto
+//! itself.
+
+use crate::domain::game_engine::models::state::{Board, BoardSize, CellStatus, Position};
+
+/// One of the eight symmetries of a board, with `x` growing rightwards and
+/// `y` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform
+{
+    /// Leaves the board unchanged.
+    Identity,
+    /// Rotates the board a quarter turn clockwise.
+    Rotate90,
+    /// Rotates the board a half turn.
+    Rotate180,
+    /// Rotates the board a quarter turn counterclockwise.
+    Rotate270,
+    /// Mirrors the board left to right.
+    FlipHorizontal,
+    /// Mirrors the board top to bottom.
+    FlipVertical,
+    /// Mirrors the board along its main diagonal, swapping `x` and `y`.
+    Transpose,
+    /// Mirrors the board along its anti-diagonal.
+    AntiTranspose,
+}
+
+impl Transform
+{
+    /// Every symmetry of a square board.
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    /// Checks whether the transform swaps the axes of the board.
+    pub const fn swaps_axes(
+        &self,
+    ) -> bool
+    {
+        matches!(
+            self,
+            Transform::Rotate90 | Transform::Rotate270 | Transform::Transpose | Transform::AntiTranspose
+        )
+    }
+
+    /// Returns the transform undoing this one.
+    pub const fn inverse(
+        &self,
+    ) -> Transform
+    {
+        match self {
+            Transform::Rotate90 => Transform::Rotate270,
+            Transform::Rotate270 => Transform::Rotate90,
+            _ => *self,
+        }
+    }
+
+    /// Returns the size of a board once transformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the board before the transform.
+    pub const fn size(
+        &self,
+        size: BoardSize,
+    ) -> BoardSize
+    {
+        if self.swaps_axes() {
+            Position { x: size.y, y: size.x }
+        } else {
+            size
+        }
+    }
+}
+
+impl Position
+{
+    /// Returns where a position lands once its board is transformed, `None`
+    /// if the position is outside of the board.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The transform to apply.
+    /// * `size` - The size of the board before the transform.
+    pub fn transform(
+        &self,
+        transform: Transform,
+        size: BoardSize,
+    ) -> Option<Position>
+    {
+        let (x, y) = (self.x, self.y);
+
+        // Also rules out empty boards, so the last row and column exist.
+        if x >= size.x || y >= size.y {
+            return None;
+        }
+
+        let (last_x, last_y) = (size.x - 1, size.y - 1);
+
+        Some(match transform {
+            Transform::Identity => Position::new(x, y),
+            Transform::Rotate90 => Position::new(last_y - y, x),
+            Transform::Rotate180 => Position::new(last_x - x, last_y - y),
+            Transform::Rotate270 => Position::new(y, last_x - x),
+            Transform::FlipHorizontal => Position::new(last_x - x, y),
+            Transform::FlipVertical => Position::new(x, last_y - y),
+            Transform::Transpose => Position::new(y, x),
+            Transform::AntiTranspose => Position::new(last_y - y, last_x - x),
+        })
+    }
+}
+
+impl Board
+{
+    /// Returns a copy of the board with a transform applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The transform to apply.
+    pub fn transform(
+        &self,
+        transform: Transform,
+    ) -> Board
+    {
+        // Transforms only exchange the width and the height, which both
+        // are valid sizes.
+        let mut board = Board::new(transform.size(self.size))
+            .expect("a transformed board has the size of a board");
+
+        for x in 0..self.size.x {
+            for y in 0..self.size.y {
+                let position = Position::new(x, y);
+
+                if let (Some(status @ (CellStatus::Black | CellStatus::White)), Some(transformed)) =
+                    (self.cell(position), position.transform(transform, self.size))
+                {
+                    let _ = board.set_cell(transformed, status);
+                }
+            }
+        }
+
+        board
+    }
+
+    /// Returns the canonical orientation of the board, the same for every
+    /// symmetric position, along with the transform producing it from this
+    /// board.
+    ///
+    /// Only the transforms keeping the size of the board are considered, so
+    /// the canonical orientation of a rectangular board is chosen among four
+    /// candidates. A position of the canonical board, such as a book move,
+    /// is mapped back onto this board with the inverse transform.
+    pub fn canonical(
+        &self,
+    ) -> (Board, Transform)
+    {
+        Transform::ALL
+            .into_iter()
+            .filter(|transform| transform.size(self.size) == self.size)
+            .map(|transform| (self.transform(transform), transform))
+            .min_by(|(a, _), (b, _)| a.rows().cmp(&b.rows()))
+            .unwrap_or_else(|| (self.clone(), Transform::Identity))
+    }
+}
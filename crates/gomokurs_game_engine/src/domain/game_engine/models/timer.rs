@@ -4,118 +4,509 @@
 //! It supports pausing and resuming the timer, resetting the elapsed time,
 //! and querying the remaining duration of the timer.
 
-use tokio::time::{sleep, Duration, Instant};
+use tokio::time::Duration;
 use tokio::sync::{Mutex, Notify};
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
+use thiserror::Error;
+use crate::domain::game_engine::models::clock::{Clock, TokioClock};
+use crate::domain::game_engine::models::state::TimeoutKind;
 
-/// The `Timer` struct represents a configurable timer used to manage turn and 
+/// Defines the time given back to a player's match clock after each move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeControl {
+    /// No time is given back.
+    #[default]
+    None,
+    /// Fischer increment: a fixed amount of time is added after each move.
+    Increment(Duration),
+    /// Bronstein delay: the time spent on a move is given back, up to a
+    /// fixed amount.
+    Delay(Duration),
+}
+
+impl TimeControl {
+    /// Returns the time given back after a move.
+    ///
+    /// # Arguments
+    /// * `time_used` - The time the player spent on the move.
+    pub fn credit(
+        &self,
+        time_used: Duration,
+    ) -> Duration
+    {
+        match *self {
+            TimeControl::None => Duration::ZERO,
+            TimeControl::Increment(increment) => increment,
+            TimeControl::Delay(delay) => time_used.min(delay),
+        }
+    }
+
+    /// Returns the time a move may last before it starts consuming the
+    /// main time, zero unless it is a Bronstein delay.
+    pub fn delay(
+        &self,
+    ) -> Duration
+    {
+        match *self {
+            TimeControl::Delay(delay) => delay,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            TimeControl::None => write!(f, "none"),
+            TimeControl::Increment(increment) => write!(f, "increment:{}", increment.as_secs_f64()),
+            TimeControl::Delay(delay) => write!(f, "delay:{}", delay.as_secs_f64()),
+        }
+    }
+}
+
+impl FromStr for TimeControl {
+    type Err = ParseTimeControlError;
+
+    /// Parses `none`, `increment:SECONDS` or `delay:SECONDS`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lowercase = s.to_lowercase();
+        let unknown = || ParseTimeControlError::UnknownTimeControl(s.to_string());
+
+        let (kind, seconds) = match lowercase.split_once(':') {
+            None if lowercase == "none" => return Ok(TimeControl::None),
+            None => return Err(unknown()),
+            Some(parts) => parts,
+        };
+        let duration = seconds.parse::<f64>()
+            .ok()
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .ok_or_else(unknown)?;
+
+        match kind {
+            "increment" => Ok(TimeControl::Increment(duration)),
+            "delay" => Ok(TimeControl::Delay(duration)),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+/// Errors that may occur while parsing a time control from its textual
+/// representation.
+#[derive(Debug, Error)]
+pub enum ParseTimeControlError {
+    /// The time control is not supported by the game engine.
+    #[error("unknown time control `{0}`, expected `none`, `increment:SECONDS` or `delay:SECONDS`")]
+    UnknownTimeControl(String),
+    /// The byo-yomi is not made of a number of periods and their duration.
+    #[error("invalid byo-yomi `{0}`, expected `PERIODS:SECONDS`")]
+    InvalidByoYomi(String),
+}
+
+/// Japanese byo-yomi: once the main time runs out, the player gets a number
+/// of periods of a fixed duration. A period is used up only when a move
+/// lasts longer than it, the next move starting a fresh period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByoYomi {
+    /// The number of periods.
+    pub periods: u32,
+    /// The duration of each period.
+    pub period: Duration,
+}
+
+impl fmt::Display for ByoYomi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.periods, self.period.as_secs_f64())
+    }
+}
+
+impl FromStr for ByoYomi {
+    type Err = ParseTimeControlError;
+
+    /// Parses `PERIODS:SECONDS`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseTimeControlError::InvalidByoYomi(s.to_string());
+
+        let (periods, seconds) = s.split_once(':').ok_or_else(invalid)?;
+        let periods = periods.parse::<u32>().map_err(|_| invalid())?;
+        let period = seconds.parse::<f64>()
+            .ok()
+            .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            .filter(|period| !period.is_zero())
+            .ok_or_else(invalid)?;
+
+        Ok(ByoYomi { periods, period })
+    }
+}
+
+/// The time left to a player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimeLeft {
+    /// The main time left, including the time earned through the time
+    /// control.
+    pub main_time: Duration,
+    /// The byo-yomi periods left.
+    pub periods: u32,
+    /// The duration of a byo-yomi period, zero without byo-yomi.
+    pub period: Duration,
+    /// The time the current turn may last without losing the game or a
+    /// byo-yomi period.
+    pub turn: Duration,
+}
+
+impl TimeLeft {
+    /// Returns the time a player may spend before losing on time, if spent
+    /// on a single move.
+    pub fn total(&self) -> Duration {
+        self.main_time + self.period * self.periods
+    }
+}
+
+/// The match time consumed and earned by a timer at some point of a game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimerSnapshot {
+    /// The match time consumed.
+    pub elapsed: Duration,
+    /// The time added to the match duration by the time control.
+    pub credit: Duration,
+    /// The byo-yomi periods used up.
+    pub periods_used: u32,
+}
+
+/// The `Timer` struct represents a configurable timer used to manage turn and
 /// match durations in a Gomoku game.
 ///
 /// The `Timer` consists of two internal components:
-/// 
-/// - **Turn Timer**: Tracks the time allotted for a single player's turn. 
-///   This timer is reset at the start of each player's turn and runs for the 
+///
+/// - **Turn Timer**: Tracks the time allotted for a single player's turn.
+///   This timer is reset at the start of each player's turn and runs for the
 ///   specified `turn_duration`.
 ///
-/// - **Match Timer**: Tracks the cumulative time a player spends on their turns 
-///   throughout the entire match. This timer runs only during a player's active 
+/// - **Match Timer**: Tracks the cumulative time a player spends on their turns
+///   throughout the entire match. This timer runs only during a player's active
 ///   turns and is limited by the specified `match_duration`.
 ///
+/// The time control gives time back to the match duration after each move.
+/// With a Bronstein delay, the time given back is known to be earned while
+/// the turn runs, so the match duration only runs out once the delay is
+/// over.
+///
+/// With byo-yomi, the periods follow once the match duration is consumed,
+/// and the time spent in them is not taken from the match duration.
+///
 /// If either timer expires, the corresponding player loses the game.
+///
+/// Time is measured with a [`Clock`], the tokio clock unless another one is
+/// given.
 #[derive(Debug, Clone)]
-pub struct Timer {
+pub struct Timer<C = TokioClock> {
     /// The duration of a single turn.
     turn_duration: Duration,
     /// The total duration of the match.
     match_duration: Duration,
-    /// Tracks the elapsed time.
-    elapsed: Arc<Mutex<Duration>>,
-    /// Notify handle for pausing the timer.
-    notify_pause: Arc<Notify>,
-    /// Notify handle for resuming the timer.
-    notify_resume: Arc<Notify>,
+    /// The time given back after each move.
+    time_control: TimeControl,
+    /// The byo-yomi following the match duration, if any.
+    byo_yomi: Option<ByoYomi>,
+    /// Tracks the elapsed time and whether the timer is running.
+    state: Arc<Mutex<TimerState>>,
+    /// Notify handle for changes of the timer state.
+    notify: Arc<Notify>,
+    /// The clock measuring the time.
+    clock: C,
+}
+
+/// The mutable state of a `Timer`.
+#[derive(Debug, Clone, Copy, Default)]
+struct TimerState {
+    /// The match time consumed by previous turns.
+    elapsed: Duration,
+    /// The time added to the match duration by the time control.
+    credit: Duration,
+    /// The byo-yomi periods used up by previous turns.
+    periods_used: u32,
+    /// The time of the clock the current turn started at, if the timer is
+    /// running.
+    running_since: Option<Duration>,
+}
+
+impl TimerState {
+    /// Returns the time elapsed since the current turn started, zero if the
+    /// timer is paused.
+    ///
+    /// # Arguments
+    /// * `now` - The current time of the clock.
+    fn turn(&self, now: Duration) -> Duration {
+        self.running_since.map_or(Duration::ZERO, |since| now.saturating_sub(since))
+    }
+
+    /// Returns the match time consumed, including the current turn.
+    ///
+    /// # Arguments
+    /// * `now` - The current time of the clock.
+    fn total_elapsed(&self, now: Duration) -> Duration {
+        self.elapsed + self.turn(now)
+    }
 }
 
 impl Timer {
-    /// Creates a new `Timer` instance with the specified turn and match
-    /// durations.
+    /// Creates a new paused `Timer` instance with the specified turn and
+    /// match durations, measuring time with the tokio clock.
     ///
     /// # Arguments
     /// * `turn_duration` - The duration of a single turn.
     /// * `match_duration` - The total duration of the match.
+    /// * `time_control` - The time given back after each move.
+    /// * `byo_yomi` - The byo-yomi following the match duration, if any.
     ///
     /// # Returns
     /// A new `Timer` instance.
     pub fn new(
         turn_duration: Duration,
         match_duration: Duration,
+        time_control: TimeControl,
+        byo_yomi: Option<ByoYomi>,
+    ) -> Self
+    {
+        Self::with_clock(turn_duration, match_duration, time_control, byo_yomi, TokioClock::new())
+    }
+}
+
+impl<C: Clock> Timer<C> {
+    /// Creates a new paused `Timer` instance with the specified turn and
+    /// match durations, measuring time with a given clock.
+    ///
+    /// # Arguments
+    /// * `turn_duration` - The duration of a single turn.
+    /// * `match_duration` - The total duration of the match.
+    /// * `time_control` - The time given back after each move.
+    /// * `byo_yomi` - The byo-yomi following the match duration, if any.
+    /// * `clock` - The clock measuring the time.
+    ///
+    /// # Returns
+    /// A new `Timer` instance.
+    pub fn with_clock(
+        turn_duration: Duration,
+        match_duration: Duration,
+        time_control: TimeControl,
+        byo_yomi: Option<ByoYomi>,
+        clock: C,
     ) -> Self
     {
         Self {
             turn_duration,
             match_duration,
-            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
-            notify_pause: Arc::new(Notify::new()),
-            notify_resume: Arc::new(Notify::new()),
+            time_control,
+            byo_yomi,
+            state: Arc::new(Mutex::new(TimerState::default())),
+            notify: Arc::new(Notify::new()),
+            clock,
         }
     }
 
-    /// Runs the timer, starting in a paused state if specified. Returns when
-    /// the timer runs out.
-    ///
-    /// # Arguments
-    /// * `start_paused` - If `true`, the timer starts in a paused state.
+    /// Returns the duration of a single turn.
+    pub fn turn_duration(&self) -> Duration {
+        self.turn_duration
+    }
+
+    /// Returns the total duration of the match.
+    pub fn match_duration(&self) -> Duration {
+        self.match_duration
+    }
+
+    /// Returns the time given back after each move.
+    pub fn time_control(&self) -> TimeControl {
+        self.time_control
+    }
+
+    /// Waits for the timer to run out, following its pauses and resumes.
+    /// Returns which of the turn or the match duration expired.
     pub async fn run(
         &self,
-        start_paused: bool,
-    ) {
-        if start_paused {
-            self.pause().await;
-        }
-
+    ) -> TimeoutKind {
         loop {
-            let start_time = Instant::now();
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
 
-            tokio::select! {
-                // Sleep for the turn duration
-                _ = sleep(self.turn_duration) => {
-                    return;
-                },
-                _ = sleep(self.get_remaining().await) => {
-                    return;
+            let state = *self.state.lock().await;
+
+            match state.running_since {
+                Some(since) => {
+                    let time_left = self.time_left_at(&state, Duration::ZERO);
+                    // The delay is earned back as the turn runs, pushing the
+                    // end of the match duration.
+                    let total = time_left.total() + self.time_control.delay();
+                    let (limit, kind) = if self.turn_duration < total {
+                        (self.turn_duration, TimeoutKind::Turn)
+                    } else if let Some(byo_yomi) = self.byo_yomi.filter(|_| time_left.periods > 0) {
+                        (total, TimeoutKind::Period(byo_yomi.periods))
+                    } else {
+                        (total, TimeoutKind::Match)
+                    };
+
+                    tokio::select! {
+                        _ = self.clock.sleep_until(since + limit) => {
+                            return kind;
+                        },
+                        // Re-evaluate the deadline if the timer was paused,
+                        // resumed or modified.
+                        _ = notified => continue,
+                    }
                 },
-                // Pause timer if a pause notification was send.
-                _ = self.notify_pause.notified() => {
-                    *self.elapsed.lock().await = start_time.elapsed();
-                    self.notify_resume.notified().await;
-    
-                    continue;
-                }
+                None => notified.await,
             }
         }
     }
 
-    /// Pauses the timer by sending a pause notification.
-    pub async fn pause(&self) {
-        self.notify_pause.notify_one();
+    /// Pauses the timer, adding the current turn to the elapsed time and
+    /// giving back the time earned through the time control. The byo-yomi
+    /// periods the turn went over are used up.
+    ///
+    /// # Returns
+    /// The duration of the current turn, zero if the timer was paused.
+    pub async fn pause(&self) -> Duration {
+        let mut state = self.state.lock().await;
+        let now = self.clock.now();
+
+        // Pausing a paused timer ends no turn, so earns nothing.
+        if state.running_since.is_none() {
+            return Duration::ZERO;
+        }
+
+        let turn = state.turn(now);
+        let main_time = self.main_time(&state) + self.time_control.delay();
+        let mut main_turn = turn;
+
+        state.running_since = None;
+
+        if let Some(byo_yomi) = self.byo_yomi {
+            let overtime = turn.saturating_sub(main_time);
+            let periods = (overtime.as_nanos() / byo_yomi.period.as_nanos()) as u32;
+
+            // The time spent in byo-yomi is not taken from the main time.
+            main_turn -= overtime;
+            state.periods_used = (state.periods_used + periods).min(byo_yomi.periods);
+        }
+        state.elapsed += main_turn;
+        state.credit += self.time_control.credit(main_turn);
+
+        self.notify.notify_waiters();
+
+        turn
     }
 
-    /// Resumes the timer by sending a resume notification.
+    /// Resumes the timer, starting a new turn. Does nothing if the timer is
+    /// already running.
     pub async fn resume(&self) {
-        self.notify_resume.notify_one();
+        let mut state = self.state.lock().await;
+
+        if state.running_since.is_none() {
+            state.running_since = Some(self.clock.now());
+        }
+
+        self.notify.notify_waiters();
     }
 
-    /// Resets the timer's elapsed time to zero.
+    /// Pauses the timer and resets its elapsed time to zero.
     pub async fn reset(&self) {
-        *self.elapsed.lock().await = Duration::ZERO;
+        *self.state.lock().await = TimerState::default();
+
+        self.notify.notify_waiters();
     }
 
-    /// Gets the remaining match duration.
+    /// Adds time to the match duration.
     ///
-    /// # Returns
-    /// The remaining time before the match duration expires. If the elapsed
-    /// time exceeds the match duration, returns `Duration::ZERO`.
-    pub async fn get_remaining(&self) -> Duration {
-        self.match_duration.saturating_sub(*self.elapsed.lock().await)
+    /// # Arguments
+    /// * `time` - The time to add.
+    pub async fn credit(&self, time: Duration) {
+        self.state.lock().await.credit += time;
+
+        self.notify.notify_waiters();
+    }
+
+    /// Captures the match time consumed and earned so far.
+    pub async fn snapshot(&self) -> TimerSnapshot {
+        let state = self.state.lock().await;
+
+        TimerSnapshot {
+            elapsed: state.total_elapsed(self.clock.now()),
+            credit: state.credit,
+            periods_used: state.periods_used,
+        }
+    }
+
+    /// Restores the match time consumed and earned from a snapshot. If the
+    /// timer is running, the current turn restarts from now.
+    ///
+    /// # Arguments
+    /// * `snapshot` - The snapshot to restore.
+    pub async fn restore(&self, snapshot: TimerSnapshot) {
+        let mut state = self.state.lock().await;
+
+        state.elapsed = snapshot.elapsed;
+        state.credit = snapshot.credit;
+        state.periods_used = snapshot.periods_used;
+        if state.running_since.is_some() {
+            state.running_since = Some(self.clock.now());
+        }
+
+        self.notify.notify_waiters();
+    }
+
+    /// Gets the time left, as of now.
+    pub async fn get_time_left(&self) -> TimeLeft {
+        let state = *self.state.lock().await;
+        let turn = state.turn(self.clock.now());
+
+        self.time_left_at(&state, turn)
+    }
+
+    /// Returns the main time left before the current turn, including the
+    /// time earned.
+    fn main_time(&self, state: &TimerState) -> Duration {
+        (self.match_duration + state.credit).saturating_sub(state.elapsed)
+    }
+
+    /// Returns the time left once part of the current turn has elapsed.
+    ///
+    /// # Arguments
+    /// * `state` - The state of the timer.
+    /// * `turn` - The time elapsed since the current turn started.
+    fn time_left_at(&self, state: &TimerState, turn: Duration) -> TimeLeft {
+        let main_time = self.main_time(state);
+        // The main time only runs once the delay of the turn is over.
+        let delay = self.time_control.delay();
+        let main_turn = turn.saturating_sub(delay);
+        let delay_left = delay.saturating_sub(turn);
+        let overtime = main_turn.saturating_sub(main_time);
+
+        let (periods, period) = match self.byo_yomi {
+            Some(byo_yomi) => {
+                let periods = byo_yomi.periods.saturating_sub(state.periods_used);
+                let used = (overtime.as_nanos() / byo_yomi.period.as_nanos()) as u32;
+
+                (periods.saturating_sub(used), byo_yomi.period)
+            },
+            None => (0, Duration::ZERO),
+        };
+        let main_time = main_time.saturating_sub(main_turn);
+        // In byo-yomi, the current period started when the main time ran
+        // out or when the previous period of the turn was used up.
+        let turn_limit = if periods == 0 {
+            main_time + delay_left
+        } else if main_time.is_zero() && delay_left.is_zero() {
+            period - Duration::from_nanos((overtime.as_nanos() % period.as_nanos()) as u64)
+        } else {
+            main_time + delay_left + period
+        };
+
+        TimeLeft {
+            main_time,
+            periods,
+            period,
+            turn: turn_limit.min(self.turn_duration.saturating_sub(turn)),
+        }
     }
 }
@@ -0,0 +1,57 @@
+//! Zobrist keys identifying board positions.
+//!
+//! The key of a position is the key of its empty board, which depends on the
+//! board size, combined by exclusive or with the key of every stone on it.
+//! Keys are derived from a fixed seed with the SplitMix64 mixer rather than
+//! drawn at random, so they are stable across runs, platforms and versions
+//! and can be stored in opening books or compared between processes.
+
+use crate::domain::game_engine::models::state::{BoardSize, PlayerColor, Position};
+
+/// The seed every key is derived from.
+const SEED: u64 = 0x676f_6d6f_6b75_7273;
+
+/// Mixes a value into a well distributed 64 bits word, with the SplitMix64
+/// finalizer.
+const fn splitmix64(
+    value: u64,
+) -> u64
+{
+    let mut z = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
+
+/// Returns the key of an empty board.
+///
+/// # Arguments
+///
+/// * `size` - The size of the board.
+pub const fn empty_board_key(
+    size: BoardSize,
+) -> u64
+{
+    splitmix64(SEED ^ (1 << 32) ^ ((size.x as u64) << 8) ^ size.y as u64)
+}
+
+/// Returns the key of a stone.
+///
+/// # Arguments
+///
+/// * `color` - The color of the stone.
+/// * `position` - The position of the stone.
+pub const fn stone_key(
+    color: PlayerColor,
+    position: Position,
+) -> u64
+{
+    let color = match color {
+        PlayerColor::Black => 0,
+        PlayerColor::White => 1,
+    };
+
+    splitmix64(SEED ^ (color << 16) ^ ((position.x as u64) << 8) ^ position.y as u64)
+}
@@ -6,6 +6,41 @@ pub trait GameEngineService
         &self,
     ) -> impl std::future::Future<Output = BoardSize>;
 
+    fn get_rules(
+        &self,
+    ) -> impl std::future::Future<Output = Rules>;
+
+    fn get_record(
+        &self,
+    ) -> impl std::future::Future<Output = GameRecord>;
+
+    /// Returns the moves of the current game, without copying its record.
+    fn get_moves(
+        &self,
+    ) -> impl std::future::Future<Output = &[RecordedMove]>;
+
+    /// Returns the color of the next stone to be played.
+    fn get_turn_player(
+        &self,
+    ) -> impl std::future::Future<Output = PlayerColor>;
+
+    /// Returns the duration of a single turn.
+    fn get_turn_duration(
+        &self,
+    ) -> impl std::future::Future<Output = std::time::Duration>;
+
+    /// Returns the total duration of the match for each player.
+    fn get_match_duration(
+        &self,
+    ) -> impl std::future::Future<Output = std::time::Duration>;
+
+    /// Returns the time left to a player, including the time earned through
+    /// the time control and the byo-yomi periods left.
+    fn get_time_left(
+        &self,
+        color: PlayerColor,
+    ) -> impl std::future::Future<Output = TimeLeft>;
+
     fn run_timers(
         &self,
     ) -> impl std::future::Future<Output = Result<GameEnd, Error>>;
@@ -16,6 +51,19 @@ pub trait GameEngineService
         position: Position,
     ) -> impl std::future::Future<Output = Result<Option<GameEnd>, Error>>;
 
+    /// Takes back the last move, reverting the board, the turn player and
+    /// the timers to their state before the move. Returns the color and
+    /// position of the removed stone.
+    fn undo(
+        &mut self,
+    ) -> impl std::future::Future<Output = Result<(PlayerColor, Position), Error>>;
+
+    /// Exchanges the clocks of the players, as when they exchange colors
+    /// during the opening. The clock of the player to move keeps running.
+    fn swap_clocks(
+        &mut self,
+    ) -> impl std::future::Future<Output = ()>;
+
     fn reset(
         &mut self,
     ) -> impl std::future::Future<Output = Result<(), Error>>;
@@ -1,51 +1,164 @@
 use crate::domain::game_engine::models::*;
 use crate::domain::game_engine::ports::GameEngineService;
 use std::sync::Arc;
+use std::time::SystemTime;
 use tokio::time::Duration;
 
 #[derive(Debug, Clone)]
-pub struct Service
+pub struct CreateGameEngineConfiguration
 {
-    board: Board,
-    turn_player: PlayerColor,
-    black_player_timer: Arc<Timer>,
-    white_player_timer: Arc<Timer>,
+    pub board_size: BoardSize,
+    pub turn_duration: Duration,
+    pub match_duration: Duration,
+    pub rules: Rules,
+    /// Number of moves after which the game is declared a draw, if any.
+    pub max_moves: Option<usize>,
+    /// The time given back to the players after each move. It applies to
+    /// every game the engine runs, games needing another time control being
+    /// given their own engine, as a tournament does for each pairing.
+    pub time_control: TimeControl,
+    /// The byo-yomi following the match duration, if any.
+    pub byo_yomi: Option<ByoYomi>,
+}
+
+/// The game engine, measuring time with a [`Clock`], the tokio clock unless
+/// another one is given.
+#[derive(Debug, Clone)]
+pub struct Service<C = TokioClock>
+{
+    game: Game,
+    record: GameRecord,
+    /// Clocks of the black and white players when the turn of each recorded
+    /// move started, kept to take moves back.
+    turns_clocks: Vec<(TimerSnapshot, TimerSnapshot)>,
+    /// Clocks of the black and white players when the current turn started.
+    turn_clocks: (TimerSnapshot, TimerSnapshot),
+    black_player_timer: Arc<Timer<C>>,
+    white_player_timer: Arc<Timer<C>>,
 }
 
 impl Service
 {
     pub fn new(
-        size: BoardSize,
-        turn_duration: Duration,
-        match_duration: Duration,
-    ) -> Self {
-        Self {
-            board: Board::new(size),
-            turn_player: PlayerColor::Black,
-            black_player_timer: Arc::new(Timer::new(turn_duration, match_duration)),
-            white_player_timer: Arc::new(Timer::new(turn_duration, match_duration)),
+        cfg: CreateGameEngineConfiguration,
+    ) -> Result<Self, Error> {
+        Self::with_clock(cfg, TokioClock::new())
+    }
+}
+
+impl<C: Clock> Service<C>
+{
+    /// Creates a game engine measuring time with a given clock, shared by
+    /// the timers of both players. Fails if the board cannot have the
+    /// configured size.
+    pub fn with_clock(
+        cfg: CreateGameEngineConfiguration,
+        clock: C,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            game: Game::new(cfg.board_size, cfg.rules, cfg.max_moves)?,
+            record: GameRecord::new(cfg.board_size, cfg.rules),
+            turns_clocks: Vec::new(),
+            turn_clocks: (TimerSnapshot::default(), TimerSnapshot::default()),
+            black_player_timer: Arc::new(Timer::with_clock(cfg.turn_duration, cfg.match_duration, cfg.time_control, cfg.byo_yomi, clock.clone())),
+            white_player_timer: Arc::new(Timer::with_clock(cfg.turn_duration, cfg.match_duration, cfg.time_control, cfg.byo_yomi, clock)),
+        })
+    }
+
+    /// Returns the timers of the black and white players.
+    fn timers(
+        &self,
+    ) -> (&Timer<C>, &Timer<C>)
+    {
+        (&self.black_player_timer, &self.white_player_timer)
+    }
+
+    /// Returns the timer of a player.
+    fn timer(
+        &self,
+        color: PlayerColor,
+    ) -> &Timer<C>
+    {
+        match color {
+            PlayerColor::Black => &self.black_player_timer,
+            PlayerColor::White => &self.white_player_timer,
         }
     }
+
+    /// Captures the clocks of the black and white players.
+    async fn snapshot_clocks(
+        &self,
+    ) -> (TimerSnapshot, TimerSnapshot)
+    {
+        let (black_timer, white_timer) = self.timers();
+
+        (black_timer.snapshot().await, white_timer.snapshot().await)
+    }
 }
 
-impl GameEngineService for Service
+impl<C: Clock> GameEngineService for Service<C>
 {
     async fn get_board_size(
         &self,
     ) -> BoardSize {
-        return self.board.size;
+        self.game.board().size
+    }
+
+    async fn get_rules(
+        &self,
+    ) -> Rules {
+        self.game.rules()
+    }
+
+    async fn get_record(
+        &self,
+    ) -> GameRecord {
+        self.record.clone()
+    }
+
+    async fn get_moves(
+        &self,
+    ) -> &[RecordedMove] {
+        &self.record.moves
+    }
+
+    async fn get_turn_player(
+        &self,
+    ) -> PlayerColor {
+        self.game.turn_player()
+    }
+
+    async fn get_turn_duration(
+        &self,
+    ) -> Duration {
+        self.black_player_timer.turn_duration()
+    }
+
+    async fn get_match_duration(
+        &self,
+    ) -> Duration {
+        self.black_player_timer.match_duration()
+    }
+
+    async fn get_time_left(
+        &self,
+        color: PlayerColor,
+    ) -> TimeLeft {
+        self.timer(color).get_time_left().await
     }
 
     async fn run_timers(
         &self,
     ) -> Result<GameEnd, Error>
     {
+        self.timer(self.game.turn_player()).resume().await;
+
         tokio::select! {
-            _ = self.black_player_timer.run(false) => {
-                Ok(GameEnd::Win(PlayerColor::White))
+            kind = self.black_player_timer.run() => {
+                Ok(GameEnd::Win(PlayerColor::White, WinReason::Timeout(kind)))
             },
-            _ = self.white_player_timer.run(true) => {
-                Ok(GameEnd::Win(PlayerColor::Black))
+            kind = self.white_player_timer.run() => {
+                Ok(GameEnd::Win(PlayerColor::Black, WinReason::Timeout(kind)))
             },
         }
     }
@@ -56,40 +169,78 @@ impl GameEngineService for Service
         position: Position,
     ) -> Result<Option<GameEnd>, Error>
     {
-        if color != self.turn_player {
-            Err(Error::NotPlayerTurn(color))
-        } else {
-            self.board.set_cell(position, color.into())?;
-
-            if self.board.check_win(position).await {
-                Ok(Some(GameEnd::Win(color)))
-            } else {
-                match self.turn_player {
-                    PlayerColor::Black => {
-                        self.black_player_timer.pause().await;
-                        self.white_player_timer.resume().await;
-                    },
-                    PlayerColor::White => {
-                        self.white_player_timer.pause().await;
-                        self.black_player_timer.resume().await;
-                    },
-                };
-                self.turn_player.switch();
-
-                Ok(None)
-            }
+        if color != self.game.turn_player() {
+            return Err(Error::NotPlayerTurn(color));
+        }
+
+        let end = self.game.play(position)?;
+
+        let timer = self.timer(color);
+        let time_used = timer.pause().await;
+
+        let recorded_move = RecordedMove {
+            color,
+            position,
+            timestamp: SystemTime::now(),
+            time_used,
+            time_remaining: timer.get_time_left().await.total(),
+        };
+        self.record.moves.push(recorded_move);
+        self.turns_clocks.push(self.turn_clocks);
+
+        if end.is_none() {
+            self.timer(color.other()).resume().await;
+            self.turn_clocks = self.snapshot_clocks().await;
+        }
+
+        Ok(end)
+    }
+
+    async fn undo(
+        &mut self,
+    ) -> Result<(PlayerColor, Position), Error>
+    {
+        let clocks_before = self.turns_clocks.pop().ok_or(Error::NothingToTakeBack)?;
+        let (color, position) = self.game.undo()?;
+
+        self.record.moves.pop();
+        self.turn_clocks = clocks_before;
+
+        let (black_timer, white_timer) = self.timers();
+        black_timer.restore(clocks_before.0).await;
+        white_timer.restore(clocks_before.1).await;
+
+        self.timer(color.other()).pause().await;
+        self.timer(color).resume().await;
+
+        Ok((color, position))
+    }
+
+    async fn swap_clocks(
+        &mut self,
+    )
+    {
+        let (black_clock, white_clock) = self.snapshot_clocks().await;
+
+        let (black_timer, white_timer) = self.timers();
+        black_timer.restore(white_clock).await;
+        white_timer.restore(black_clock).await;
+
+        // Clocks kept to take moves back follow the players too.
+        for clocks in self.turns_clocks.iter_mut().chain([&mut self.turn_clocks]) {
+            *clocks = (clocks.1, clocks.0);
         }
     }
 
     async fn reset(
         &mut self,
     ) -> Result<(), Error> {
-        self.board = Board::new(self.board.size);
-        self.turn_player = PlayerColor::Black;
+        self.game.reset();
+        self.record.moves.clear();
+        self.turns_clocks.clear();
+        self.turn_clocks = (TimerSnapshot::default(), TimerSnapshot::default());
 
-        self.black_player_timer.pause().await;
         self.black_player_timer.reset().await;
-        self.white_player_timer.pause().await;
         self.white_player_timer.reset().await;
 
         Ok(())
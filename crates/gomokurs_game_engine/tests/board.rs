@@ -0,0 +1,325 @@
+//! Tests of the rows measured on the bitboards, compared to a plain cell
+//! grid walked one cell at a time as the engine used to.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+
+/// The board the engine used before bitboards.
+struct GridBoard
+{
+    size: BoardSize,
+    cells: Vec<Vec<CellStatus>>,
+}
+
+impl GridBoard
+{
+    fn new(
+        size: BoardSize,
+    ) -> Self
+    {
+        Self {
+            size,
+            cells: vec![vec![CellStatus::Available; size.y as usize]; size.x as usize],
+        }
+    }
+
+    fn cell_at(
+        &self,
+        x: i32,
+        y: i32,
+    ) -> Option<CellStatus>
+    {
+        if x < 0 || y < 0 || x >= self.size.x as i32 || y >= self.size.y as i32 {
+            None
+        } else {
+            Some(self.cells[x as usize][y as usize])
+        }
+    }
+
+    /// Counts the cells sharing the status of `origin` in a direction,
+    /// `origin` excluded.
+    fn walk(
+        &self,
+        origin: Position,
+        (dx, dy): (i32, i32),
+    ) -> i32
+    {
+        let status = self.cells[origin.x as usize][origin.y as usize];
+        let mut count = 0;
+
+        while self.cell_at(origin.x as i32 + dx * (count + 1), origin.y as i32 + dy * (count + 1)) == Some(status) {
+            count += 1;
+        }
+
+        count
+    }
+
+    fn row(
+        &self,
+        origin: Position,
+        axis: CheckRowAxis,
+    ) -> Row
+    {
+        let (dx, dy) = axis.value();
+        let (dx, dy) = (dx as i32, dy as i32);
+        let forward = self.walk(origin, (dx, dy));
+        let backward = self.walk(origin, (-dx, -dy));
+        let (x, y) = (origin.x as i32, origin.y as i32);
+
+        Row {
+            length: (forward + backward + 1) as u8,
+            ends: [
+                self.cell_at(x + dx * (forward + 1), y + dy * (forward + 1)),
+                self.cell_at(x - dx * (backward + 1), y - dy * (backward + 1)),
+            ],
+        }
+    }
+
+    fn winning_line(
+        &self,
+        played_move: Position,
+        rules: &Rules,
+    ) -> Option<WinningLine>
+    {
+        let color = match self.cells[played_move.x as usize][played_move.y as usize] {
+            CellStatus::Black => PlayerColor::Black,
+            CellStatus::White => PlayerColor::White,
+            CellStatus::Available => return None,
+        };
+
+        CheckRowAxis::ALL.into_iter().find_map(|axis| {
+            let row = self.row(played_move, axis);
+
+            if !rules.is_winning_row(color, &row) {
+                return None;
+            }
+
+            let (dx, dy) = axis.value();
+            let backward = self.walk(played_move, (-dx as i32, -dy as i32));
+
+            Some(WinningLine {
+                start: Position::new(
+                    (played_move.x as i32 - dx as i32 * backward) as u8,
+                    (played_move.y as i32 - dy as i32 * backward) as u8,
+                ),
+                axis,
+                length: row.length,
+            })
+        })
+    }
+}
+
+/// Fills a bitboard and a grid with the same reproducible pseudo-random
+/// stones, about a third of the cells of each color.
+fn random_boards(
+    size: BoardSize,
+    seed: u64,
+) -> (Board, GridBoard)
+{
+    let mut board = Board::new(size).unwrap();
+    let mut grid = GridBoard::new(size);
+    let mut state = seed;
+
+    for x in 0..size.x {
+        for y in 0..size.y {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let status = match state % 3 {
+                0 => CellStatus::Black,
+                1 => CellStatus::White,
+                _ => continue,
+            };
+
+            board.set_cell(Position::new(x, y), status).unwrap();
+            grid.cells[x as usize][y as usize] = status;
+        }
+    }
+
+    (board, grid)
+}
+
+/// Sizes of rectangular boards, wider or taller, up to the largest ones.
+const SIZES: [(u8, u8); 8] = [(1, 1), (3, 17), (17, 3), (7, 12), (12, 7), (64, 5), (5, 64), (64, 64)];
+
+#[test]
+fn rows_match_the_grid() {
+    for (seed, (x, y)) in SIZES.into_iter().enumerate() {
+        let size = BoardSize { x, y };
+        let (board, grid) = random_boards(size, seed as u64 + 1);
+
+        for x in 0..size.x {
+            for y in 0..size.y {
+                let position = Position::new(x, y);
+
+                for axis in CheckRowAxis::ALL {
+                    assert_eq!(
+                        board.row(position, axis),
+                        grid.row(position, axis),
+                        "{:?} row through {} on a board of {}",
+                        axis,
+                        position,
+                        size,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn winning_lines_match_the_grid() {
+    let rules: Vec<Rules> = [3, 4, 5, 6]
+        .into_iter()
+        .flat_map(|win_length| {
+            let rules = Rules { win_length, ..Rules::default() };
+
+            [
+                rules,
+                Rules { exact_length: true, ..rules },
+                Rules { caro: true, ..rules },
+                Rules { rule_set: RuleSet::Renju, ..rules },
+            ]
+        })
+        .collect();
+
+    for (seed, (x, y)) in SIZES.into_iter().enumerate() {
+        let size = BoardSize { x, y };
+        let (board, grid) = random_boards(size, seed as u64 + 0x5eed);
+
+        for x in 0..size.x {
+            for y in 0..size.y {
+                let position = Position::new(x, y);
+
+                for rules in &rules {
+                    assert_eq!(
+                        board.winning_line(position, rules),
+                        grid.winning_line(position, rules),
+                        "winning line through {} on a board of {} with {:?}",
+                        position,
+                        size,
+                        rules,
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Sets up a board with a single row of black stones from `start`.
+fn board_with_row(
+    size: BoardSize,
+    start: (u8, u8),
+    axis: CheckRowAxis,
+    length: u8,
+) -> Board
+{
+    let mut board = Board::new(size).unwrap();
+    let (dx, dy) = axis.value();
+
+    for i in 0..length as i32 {
+        let position = Position::new(
+            (start.0 as i32 + dx as i32 * i) as u8,
+            (start.1 as i32 + dy as i32 * i) as u8,
+        );
+        board.set_cell(position, CellStatus::Black).unwrap();
+    }
+
+    board
+}
+
+#[test]
+fn diagonals_reach_the_corners_of_rectangular_boards() {
+    let rules = Rules::default();
+    let cases = [
+        (BoardSize { x: 5, y: 9 }, (0, 0), CheckRowAxis::DiagonalDown),
+        (BoardSize { x: 9, y: 5 }, (4, 0), CheckRowAxis::DiagonalDown),
+        (BoardSize { x: 5, y: 9 }, (0, 8), CheckRowAxis::DiagonalUp),
+        (BoardSize { x: 9, y: 5 }, (4, 4), CheckRowAxis::DiagonalUp),
+        (BoardSize { x: 64, y: 5 }, (59, 4), CheckRowAxis::DiagonalUp),
+        (BoardSize { x: 5, y: 64 }, (0, 59), CheckRowAxis::DiagonalDown),
+    ];
+
+    for (size, start, axis) in cases {
+        let board = board_with_row(size, start, axis, 5);
+        let expected = WinningLine { start: Position::new(start.0, start.1), axis, length: 5 };
+        let (dx, dy) = axis.value();
+
+        for i in 0..5 {
+            let position = Position::new((start.0 as i8 + dx * i) as u8, (start.1 as i8 + dy * i) as u8);
+
+            assert_eq!(board.row(position, axis), Row { length: 5, ends: [None, None] });
+            assert_eq!(board.winning_line(position, &rules), Some(expected), "{:?} from {:?} on {}", axis, start, size);
+        }
+    }
+}
+
+#[test]
+fn rows_touching_the_edges_are_measured() {
+    let size = BoardSize { x: 10, y: 6 };
+    let rules = Rules::default();
+
+    let board = board_with_row(size, (0, 3), CheckRowAxis::Horizontal, 5);
+    assert_eq!(board.row(Position::new(0, 3), CheckRowAxis::Horizontal), Row {
+        length: 5,
+        ends: [Some(CellStatus::Available), None],
+    });
+    assert!(board.check_win(Position::new(4, 3), &rules));
+
+    let board = board_with_row(size, (5, 0), CheckRowAxis::Horizontal, 5);
+    assert_eq!(board.row(Position::new(9, 0), CheckRowAxis::Horizontal), Row {
+        length: 5,
+        ends: [None, Some(CellStatus::Available)],
+    });
+    assert!(board.check_win(Position::new(7, 0), &rules));
+
+    let board = board_with_row(size, (9, 1), CheckRowAxis::Vertical, 5);
+    assert_eq!(board.row(Position::new(9, 5), CheckRowAxis::Vertical), Row {
+        length: 5,
+        ends: [None, Some(CellStatus::Available)],
+    });
+
+    let board = board_with_row(size, (9, 1), CheckRowAxis::Vertical, 4);
+    assert!(!board.check_win(Position::new(9, 4), &rules));
+}
+
+#[test]
+fn win_length_decides_the_winning_rows() {
+    let size = BoardSize { x: 8, y: 4 };
+    let board = board_with_row(size, (2, 0), CheckRowAxis::DiagonalDown, 4);
+    let line = WinningLine { start: Position::new(2, 0), axis: CheckRowAxis::DiagonalDown, length: 4 };
+
+    for (win_length, expected) in [(3, Some(line)), (4, Some(line)), (5, None)] {
+        let rules = Rules { win_length, ..Rules::default() };
+
+        assert_eq!(board.winning_line(Position::new(4, 2), &rules), expected, "win length {}", win_length);
+    }
+
+    let exact = Rules { win_length: 3, exact_length: true, ..Rules::default() };
+    assert_eq!(board.winning_line(Position::new(4, 2), &exact), None);
+}
+
+#[test]
+fn unsupported_sizes_are_rejected() {
+    for size in [BoardSize { x: 0, y: 15 }, BoardSize { x: 15, y: 0 }, BoardSize { x: MAX_BOARD_SIZE + 1, y: 15 }] {
+        assert!(matches!(Board::new(size), Err(BoardSizeError::OutOfRange(s)) if s == size), "size {}", size);
+    }
+
+    assert!(Board::new(BoardSize { x: MAX_BOARD_SIZE, y: 1 }).is_ok());
+}
+
+#[test]
+fn cells_mirror_the_bitboards() {
+    let mut board = Board::new(BoardSize { x: 7, y: 5 }).unwrap();
+
+    board.set_cell(Position::new(6, 4), CellStatus::Black).unwrap();
+    board.set_cell(Position::new(0, 2), CellStatus::White).unwrap();
+    assert_eq!(board.cells[6][4], CellStatus::Black);
+    assert_eq!(board.cells[0][2], CellStatus::White);
+
+    board.clear_cell(Position::new(6, 4)).unwrap();
+    assert_eq!(board.cells[6][4], CellStatus::Available);
+
+    board.clear();
+    assert!(board.cells.iter().flatten().all(|cell| *cell == CellStatus::Available));
+}
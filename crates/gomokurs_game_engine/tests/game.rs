@@ -0,0 +1,74 @@
+//! Tests of the end of games on a 3x3 board where three in a row wins.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+
+fn game(
+    max_moves: Option<usize>,
+) -> Game
+{
+    let rules = Rules { win_length: 3, ..Rules::default() };
+
+    Game::new(BoardSize { x: 3, y: 3 }, rules, max_moves).unwrap()
+}
+
+/// Plays the given moves, returning the outcome after the last one.
+fn play(
+    game: &mut Game,
+    moves: &[(u8, u8)],
+) -> Option<GameEnd>
+{
+    let mut outcome = None;
+
+    for &(x, y) in moves {
+        assert_eq!(outcome, None);
+        outcome = game.play(Position::new(x, y)).unwrap();
+    }
+
+    outcome
+}
+
+/// Fills the board without any row of three:
+///
+/// ```text
+/// X O X
+/// X O O
+/// O X X
+/// ```
+const FULL_BOARD: [(u8, u8); 9] = [(0, 0), (1, 0), (2, 0), (1, 1), (0, 1), (2, 1), (1, 2), (0, 2), (2, 2)];
+
+#[test]
+fn full_board_is_a_draw() {
+    let mut game = game(None);
+
+    assert_eq!(play(&mut game, &FULL_BOARD), Some(GameEnd::Draw(DrawReason::FullBoard)));
+    assert_eq!(game.outcome(), Some(GameEnd::Draw(DrawReason::FullBoard)));
+    assert!(matches!(game.play(Position::new(0, 0)), Err(Error::GameOver)));
+}
+
+#[test]
+fn move_limit_is_a_draw() {
+    let mut game = game(Some(4));
+
+    assert_eq!(play(&mut game, &FULL_BOARD[..4]), Some(GameEnd::Draw(DrawReason::MoveLimit)));
+    assert!(matches!(game.play(Position::new(2, 1)), Err(Error::GameOver)));
+}
+
+#[test]
+fn win_on_the_last_move_is_not_a_draw() {
+    let mut game = game(Some(5));
+
+    let outcome = play(&mut game, &[(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)]);
+
+    assert!(matches!(outcome, Some(GameEnd::Win(PlayerColor::Black, _))));
+}
+
+#[test]
+fn undoing_a_draw_resumes_the_game() {
+    let mut game = game(Some(4));
+
+    play(&mut game, &FULL_BOARD[..4]);
+    game.undo().unwrap();
+
+    assert_eq!(game.outcome(), None);
+    assert_eq!(game.play(Position::new(1, 1)).unwrap(), Some(GameEnd::Draw(DrawReason::MoveLimit)));
+}
@@ -0,0 +1,84 @@
+//! Tests of the Pro and Long Pro restrictions on black's first stones.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+
+/// Sets up a game on a standard 15x15 board, whose center is 7,7, and
+/// plays the given moves.
+fn game(
+    opening_restriction: OpeningRestriction,
+    moves: &[(u8, u8)],
+) -> Game
+{
+    let rules = Rules { opening_restriction, ..Rules::default() };
+    let mut game = Game::new(BoardSize { x: 15, y: 15 }, rules, None).unwrap();
+
+    for &(x, y) in moves {
+        game.play(Position::new(x, y)).unwrap();
+    }
+
+    game
+}
+
+fn violates_restriction(
+    game: &Game,
+    x: u8,
+    y: u8,
+) -> bool
+{
+    matches!(game.check_move(Position::new(x, y)), Err(Error::OpeningRestrictionViolation { .. }))
+}
+
+#[test]
+fn first_stone_goes_in_the_center() {
+    for restriction in [OpeningRestriction::Pro, OpeningRestriction::LongPro] {
+        let game = game(restriction, &[]);
+
+        assert!(violates_restriction(&game, 0, 0));
+        assert!(violates_restriction(&game, 7, 8));
+        assert!(game.check_move(Position::new(7, 7)).is_ok());
+    }
+}
+
+#[test]
+fn center_of_a_rectangular_board() {
+    let rules = Rules { opening_restriction: OpeningRestriction::Pro, ..Rules::default() };
+    let game = Game::new(BoardSize { x: 20, y: 11 }, rules, None).unwrap();
+
+    assert!(violates_restriction(&game, 5, 10));
+    assert!(game.check_move(Position::new(10, 5)).is_ok());
+}
+
+#[test]
+fn pro_second_stone_is_three_away_from_the_center() {
+    let game = game(OpeningRestriction::Pro, &[(7, 7), (7, 8)]);
+
+    assert!(violates_restriction(&game, 9, 9));
+    assert!(violates_restriction(&game, 5, 6));
+    assert!(game.check_move(Position::new(10, 7)).is_ok());
+    assert!(game.check_move(Position::new(4, 9)).is_ok());
+}
+
+#[test]
+fn long_pro_second_stone_is_four_away_from_the_center() {
+    let game = game(OpeningRestriction::LongPro, &[(7, 7), (7, 8)]);
+
+    assert!(violates_restriction(&game, 10, 7));
+    assert!(violates_restriction(&game, 4, 10));
+    assert!(game.check_move(Position::new(11, 7)).is_ok());
+    assert!(game.check_move(Position::new(3, 3)).is_ok());
+}
+
+#[test]
+fn white_and_later_black_stones_are_unrestricted() {
+    // White places its stones next to the center.
+    let game = game(OpeningRestriction::LongPro, &[(7, 7), (7, 8), (11, 7), (6, 6)]);
+
+    assert!(game.check_move(Position::new(8, 8)).is_ok());
+}
+
+#[test]
+fn no_restriction_allows_any_first_stones() {
+    let game = game(OpeningRestriction::None, &[(0, 0), (7, 7)]);
+
+    assert!(game.check_move(Position::new(1, 1)).is_ok());
+}
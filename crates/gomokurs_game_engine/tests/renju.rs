@@ -0,0 +1,152 @@
+//! Tests of the detection of the moves forbidden to black under renju rules.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+
+/// Sets up a standard 15x15 board with the given stones.
+fn board(
+    black: &[(u8, u8)],
+    white: &[(u8, u8)],
+) -> Board
+{
+    let mut board = Board::new(BoardSize { x: 15, y: 15 }).unwrap();
+
+    for &(x, y) in black {
+        board.set_cell(Position::new(x, y), CellStatus::Black).unwrap();
+    }
+    for &(x, y) in white {
+        board.set_cell(Position::new(x, y), CellStatus::White).unwrap();
+    }
+
+    board
+}
+
+fn forbidden_move(
+    board: &Board,
+    x: u8,
+    y: u8,
+) -> Option<ForbiddenMove>
+{
+    board.forbidden_move(Position::new(x, y), 5)
+}
+
+#[test]
+fn two_open_threes_are_a_double_three() {
+    let board = board(&[(5, 7), (6, 7), (7, 5), (7, 6)], &[]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), Some(ForbiddenMove::DoubleThree));
+}
+
+#[test]
+fn blocked_three_is_not_open() {
+    let board = board(&[(5, 7), (6, 7), (7, 5), (7, 6)], &[(4, 7), (8, 7)]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), None);
+}
+
+#[test]
+fn three_whose_straight_four_is_forbidden_is_not_open() {
+    // The horizontal three can only become a straight four at (7, 7), which
+    // also makes a vertical four and is therefore a double four.
+    let black = [(4, 7), (5, 7), (6, 5), (6, 6), (7, 9), (7, 10), (7, 11)];
+    let mut board = board(&black, &[(2, 7)]);
+
+    assert_eq!(forbidden_move(&board, 6, 7), None);
+
+    board.set_cell(Position::new(6, 7), CellStatus::Black).unwrap();
+    assert_eq!(forbidden_move(&board, 7, 7), Some(ForbiddenMove::DoubleFour));
+}
+
+#[test]
+fn three_whose_straight_four_is_allowed_is_open() {
+    let black = [(4, 7), (5, 7), (6, 5), (6, 6)];
+    let board = board(&black, &[(2, 7)]);
+
+    assert_eq!(forbidden_move(&board, 6, 7), Some(ForbiddenMove::DoubleThree));
+}
+
+#[test]
+fn three_whose_straight_four_makes_an_overline_is_not_open() {
+    let black = [(1, 7), (4, 7), (5, 7), (9, 7), (6, 5), (6, 6)];
+    let board = board(&black, &[]);
+
+    assert_eq!(forbidden_move(&board, 6, 7), None);
+}
+
+#[test]
+fn two_fours_are_a_double_four() {
+    let board = board(&[(4, 7), (5, 7), (6, 7), (7, 4), (7, 5), (7, 6)], &[(3, 7), (7, 3)]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), Some(ForbiddenMove::DoubleFour));
+}
+
+#[test]
+fn two_fours_on_a_single_line_are_a_double_four() {
+    let board = board(&[(2, 7), (4, 7), (5, 7), (8, 7)], &[]);
+
+    assert_eq!(forbidden_move(&board, 6, 7), Some(ForbiddenMove::DoubleFour));
+}
+
+#[test]
+fn straight_four_is_a_single_four() {
+    let board = board(&[(4, 7), (5, 7), (6, 7), (7, 4), (7, 5)], &[]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), None);
+}
+
+#[test]
+fn four_three_is_allowed() {
+    let board = board(&[(4, 7), (5, 7), (6, 7), (7, 5), (7, 6)], &[(3, 7)]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), None);
+}
+
+#[test]
+fn six_in_a_row_is_an_overline() {
+    let board = board(&[(3, 7), (4, 7), (5, 7), (6, 7), (8, 7)], &[]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), Some(ForbiddenMove::Overline));
+}
+
+#[test]
+fn exact_five_is_allowed() {
+    let board = board(&[(3, 7), (4, 7), (5, 7), (6, 7)], &[]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), None);
+}
+
+#[test]
+fn exact_five_wins_over_other_patterns() {
+    // The move also makes a vertical and a diagonal four.
+    let black = [(3, 7), (4, 7), (5, 7), (6, 7), (7, 4), (7, 5), (7, 6), (4, 4), (5, 5), (6, 6)];
+    let board = board(&black, &[]);
+
+    assert_eq!(forbidden_move(&board, 7, 7), None);
+}
+
+#[test]
+fn three_against_the_edge_is_not_open() {
+    let board = board(&[(0, 7), (1, 7), (2, 5), (2, 6)], &[]);
+
+    assert_eq!(forbidden_move(&board, 2, 7), None);
+}
+
+#[test]
+fn three_near_the_edge_is_open() {
+    let board = board(&[(1, 7), (2, 7), (3, 5), (3, 6)], &[]);
+
+    assert_eq!(forbidden_move(&board, 3, 7), Some(ForbiddenMove::DoubleThree));
+}
+
+#[test]
+fn four_against_the_edge_counts() {
+    let board = board(&[(0, 7), (1, 7), (2, 7), (3, 9), (3, 10), (3, 11)], &[]);
+
+    assert_eq!(forbidden_move(&board, 3, 7), Some(ForbiddenMove::DoubleFour));
+}
+
+#[test]
+fn overline_against_the_corner_is_detected() {
+    let board = board(&[(0, 0), (1, 1), (2, 2), (4, 4), (5, 5)], &[]);
+
+    assert_eq!(forbidden_move(&board, 3, 3), Some(ForbiddenMove::Overline));
+}
@@ -0,0 +1,126 @@
+//! Tests of the symmetries of the board.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+
+/// Sizes of square and rectangular boards.
+const SIZES: [(u8, u8); 4] = [(15, 15), (20, 20), (7, 12), (19, 5)];
+
+/// Sets up a board with reproducible pseudo-random stones, about a sixth of
+/// the cells of each color.
+fn random_board(
+    size: BoardSize,
+    seed: u64,
+) -> Board
+{
+    let mut board = Board::new(size).unwrap();
+    let mut state = seed;
+
+    for x in 0..size.x {
+        for y in 0..size.y {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            let status = match state % 6 {
+                0 => CellStatus::Black,
+                1 => CellStatus::White,
+                _ => continue,
+            };
+            board.set_cell(Position::new(x, y), status).unwrap();
+        }
+    }
+
+    board
+}
+
+#[test]
+fn positions_round_trip_through_the_inverse() {
+    for (x, y) in SIZES {
+        let size = BoardSize { x, y };
+
+        for transform in Transform::ALL {
+            let transformed_size = transform.size(size);
+
+            assert_eq!(transform.inverse().size(transformed_size), size);
+
+            for x in 0..size.x {
+                for y in 0..size.y {
+                    let position = Position::new(x, y);
+                    let transformed = position.transform(transform, size).unwrap();
+
+                    assert!(transformed.x < transformed_size.x && transformed.y < transformed_size.y);
+                    assert_eq!(
+                        transformed.transform(transform.inverse(), transformed_size),
+                        Some(position),
+                        "{:?} of {} on a board of {}",
+                        transform,
+                        position,
+                        size,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn boards_round_trip_through_the_inverse() {
+    for (seed, (x, y)) in SIZES.into_iter().enumerate() {
+        let board = random_board(BoardSize { x, y }, seed as u64 + 1);
+
+        for transform in Transform::ALL {
+            let transformed = board.transform(transform);
+
+            assert_eq!(transformed.size, transform.size(board.size));
+            assert_eq!(transformed.transform(transform.inverse()), board, "{:?}", transform);
+        }
+    }
+}
+
+#[test]
+fn symmetric_boards_share_their_canonical_board() {
+    for (seed, (x, y)) in SIZES.into_iter().enumerate() {
+        let board = random_board(BoardSize { x, y }, seed as u64 + 0x5eed);
+        let (canonical, transform) = board.canonical();
+
+        assert_eq!(board.transform(transform), canonical);
+
+        let symmetries = Transform::ALL
+            .into_iter()
+            .filter(|transform| transform.size(board.size) == board.size);
+
+        for symmetry in symmetries {
+            let symmetric = board.transform(symmetry);
+            let (symmetric_canonical, symmetric_transform) = symmetric.canonical();
+
+            assert_eq!(symmetric_canonical, canonical, "{:?} on a board of {}", symmetry, board.size);
+            assert_eq!(symmetric.transform(symmetric_transform), canonical);
+        }
+    }
+}
+
+#[test]
+fn canonical_positions_map_back_with_the_inverse() {
+    let mut board = Board::new(BoardSize { x: 15, y: 15 }).unwrap();
+
+    board.set_cell(Position::new(2, 11), CellStatus::Black).unwrap();
+
+    let (canonical, transform) = board.canonical();
+    let stone = (0..15)
+        .flat_map(|x| (0..15).map(move |y| Position::new(x, y)))
+        .find(|position| canonical.cell(*position) == Some(CellStatus::Black))
+        .unwrap();
+
+    assert_eq!(stone.transform(transform.inverse(), canonical.size), Some(Position::new(2, 11)));
+}
+
+#[test]
+fn positions_outside_the_board_are_not_transformed() {
+    let size = BoardSize { x: 20, y: 15 };
+
+    for transform in Transform::ALL {
+        assert_eq!(Position::new(20, 0).transform(transform, size), None);
+        assert_eq!(Position::new(0, 15).transform(transform, size), None);
+        assert_eq!(Position::new(0, 0).transform(transform, BoardSize { x: 0, y: 0 }), None);
+    }
+}
@@ -0,0 +1,272 @@
+//! Tests of the timers, driven by a virtual clock in zero wall time.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+use gomokurs_game_engine::domain::game_engine::{CreateGameEngineConfiguration, GameEngine, GameEngineService};
+use tokio::time::Duration;
+use tokio_test::{assert_pending, assert_ready, assert_ready_eq, task};
+
+fn secs(
+    seconds: u64,
+) -> Duration
+{
+    Duration::from_secs(seconds)
+}
+
+fn timer(
+    turn_duration: Duration,
+    match_duration: Duration,
+    byo_yomi: Option<ByoYomi>,
+) -> (Timer<VirtualClock>, VirtualClock)
+{
+    timed_timer(turn_duration, match_duration, TimeControl::None, byo_yomi)
+}
+
+fn timed_timer(
+    turn_duration: Duration,
+    match_duration: Duration,
+    time_control: TimeControl,
+    byo_yomi: Option<ByoYomi>,
+) -> (Timer<VirtualClock>, VirtualClock)
+{
+    let clock = VirtualClock::new();
+
+    (Timer::with_clock(turn_duration, match_duration, time_control, byo_yomi, clock.clone()), clock)
+}
+
+fn engine(
+    time_control: TimeControl,
+    clock: VirtualClock,
+) -> GameEngine<VirtualClock>
+{
+    GameEngine::with_clock(CreateGameEngineConfiguration {
+        board_size: BoardSize { x: 15, y: 15 },
+        turn_duration: secs(5),
+        match_duration: secs(20),
+        rules: Rules::default(),
+        max_moves: None,
+        time_control,
+        byo_yomi: None,
+    }, clock).unwrap()
+}
+
+#[tokio::test]
+async fn turn_expires_after_turn_duration() {
+    let (timer, clock) = timer(secs(5), secs(60), None);
+    let mut run = task::spawn(timer.run());
+
+    timer.resume().await;
+    clock.advance(Duration::from_millis(4999));
+    assert_pending!(run.poll());
+
+    clock.advance(Duration::from_millis(1));
+    assert_ready_eq!(run.poll(), TimeoutKind::Turn);
+}
+
+#[tokio::test]
+async fn match_expires_across_turns() {
+    let (timer, clock) = timer(secs(10), secs(15), None);
+    let mut run = task::spawn(timer.run());
+
+    timer.resume().await;
+    clock.advance(secs(8));
+    assert_eq!(timer.pause().await, secs(8));
+    assert_pending!(run.poll());
+
+    timer.resume().await;
+    clock.advance(secs(6));
+    assert_pending!(run.poll());
+    assert_eq!(timer.get_time_left().await.main_time, secs(1));
+
+    clock.advance(secs(1));
+    assert_ready_eq!(run.poll(), TimeoutKind::Match);
+}
+
+#[tokio::test]
+async fn paused_timer_does_not_expire() {
+    let (timer, clock) = timer(secs(5), secs(10), None);
+    let mut run = task::spawn(timer.run());
+
+    timer.resume().await;
+    clock.advance(secs(3));
+    timer.pause().await;
+    clock.advance(secs(100));
+    assert_pending!(run.poll());
+    assert_eq!(timer.get_time_left().await.main_time, secs(7));
+
+    // The turn duration starts over when the timer is resumed.
+    timer.resume().await;
+    clock.advance(Duration::from_millis(4999));
+    assert_pending!(run.poll());
+
+    clock.advance(Duration::from_millis(1));
+    assert_ready_eq!(run.poll(), TimeoutKind::Turn);
+}
+
+#[tokio::test]
+async fn reset_restores_match_duration() {
+    let (timer, clock) = timer(secs(5), secs(10), None);
+    let mut run = task::spawn(timer.run());
+
+    timer.resume().await;
+    clock.advance(secs(4));
+    timer.reset().await;
+    assert_eq!(timer.get_time_left().await.main_time, secs(10));
+
+    // The timer is paused by the reset.
+    clock.advance(secs(100));
+    assert_pending!(run.poll());
+
+    timer.resume().await;
+    clock.advance(secs(5));
+    assert_ready_eq!(run.poll(), TimeoutKind::Turn);
+}
+
+#[tokio::test]
+async fn byo_yomi_periods_are_used_up_by_long_moves() {
+    let byo_yomi = ByoYomi { periods: 3, period: secs(10) };
+    let (timer, clock) = timer(secs(60), secs(5), Some(byo_yomi));
+    let mut run = task::spawn(timer.run());
+
+    // The main time runs out, but the move stays within the first period.
+    timer.resume().await;
+    clock.advance(secs(14));
+    timer.pause().await;
+    assert_eq!(timer.get_time_left().await.periods, 3);
+    assert_eq!(timer.get_time_left().await.main_time, Duration::ZERO);
+
+    // A move over one period uses it up.
+    timer.resume().await;
+    clock.advance(secs(15));
+    assert_eq!(timer.get_time_left().await.turn, secs(5));
+    timer.pause().await;
+    assert_eq!(timer.get_time_left().await.periods, 2);
+    assert_pending!(run.poll());
+
+    timer.resume().await;
+    clock.advance(Duration::from_millis(19999));
+    assert_pending!(run.poll());
+
+    clock.advance(Duration::from_millis(1));
+    assert_ready_eq!(run.poll(), TimeoutKind::Period(3));
+}
+
+#[tokio::test]
+async fn delay_pushes_the_end_of_a_short_main_time() {
+    // The main time is shorter than the delay, which is earned back while
+    // the turn runs.
+    let (timer, clock) = timed_timer(secs(60), secs(2), TimeControl::Delay(secs(3)), None);
+    let mut run = task::spawn(timer.run());
+
+    timer.resume().await;
+    clock.advance(secs(4));
+    assert_pending!(run.poll());
+    assert_eq!(timer.get_time_left().await.main_time, secs(1));
+    assert_eq!(timer.get_time_left().await.turn, secs(1));
+
+    // The main time left is charged for the turn beyond the delay.
+    clock.advance(Duration::from_millis(500));
+    timer.pause().await;
+    assert_pending!(run.poll());
+    assert_eq!(timer.get_time_left().await.main_time, Duration::from_millis(500));
+
+    // A move within the delay leaves the main time untouched.
+    timer.resume().await;
+    clock.advance(secs(2));
+    timer.pause().await;
+    assert_pending!(run.poll());
+    assert_eq!(timer.get_time_left().await.main_time, Duration::from_millis(500));
+
+    timer.resume().await;
+    clock.advance(Duration::from_millis(3499));
+    assert_pending!(run.poll());
+
+    clock.advance(Duration::from_millis(1));
+    assert_ready_eq!(run.poll(), TimeoutKind::Match);
+}
+
+#[tokio::test]
+async fn delay_comes_before_byo_yomi() {
+    let byo_yomi = ByoYomi { periods: 1, period: secs(10) };
+    let (timer, clock) = timed_timer(secs(60), secs(2), TimeControl::Delay(secs(3)), Some(byo_yomi));
+    let mut run = task::spawn(timer.run());
+
+    timer.resume().await;
+    clock.advance(secs(5));
+    assert_eq!(timer.get_time_left().await.turn, secs(10));
+    assert_eq!(timer.get_time_left().await.periods, 1);
+
+    clock.advance(Duration::from_millis(9999));
+    assert_pending!(run.poll());
+
+    clock.advance(Duration::from_millis(1));
+    assert_ready_eq!(run.poll(), TimeoutKind::Period(1));
+}
+
+#[tokio::test]
+async fn engine_reports_the_timeout_of_the_player_to_move() {
+    let clock = VirtualClock::new();
+    let mut engine = engine(TimeControl::None, clock.clone());
+    let watcher = engine.clone();
+    let mut run = task::spawn(watcher.run_timers());
+
+    assert_pending!(run.poll());
+    clock.advance(secs(2));
+    engine.register_player_move(PlayerColor::Black, Position::new(7, 7)).await.unwrap();
+    assert_pending!(run.poll());
+
+    clock.advance(secs(5));
+    assert_eq!(
+        assert_ready!(run.poll()).unwrap(),
+        GameEnd::Win(PlayerColor::Black, WinReason::Timeout(TimeoutKind::Turn))
+    );
+}
+
+#[tokio::test]
+async fn engine_credits_time_controls() {
+    let clock = VirtualClock::new();
+    let mut increment = engine(TimeControl::Increment(secs(3)), clock.clone());
+    let mut delay = engine(TimeControl::Delay(secs(3)), clock.clone());
+    let watchers = (increment.clone(), delay.clone());
+    let mut runs = (task::spawn(watchers.0.run_timers()), task::spawn(watchers.1.run_timers()));
+
+    assert_pending!(runs.0.poll());
+    assert_pending!(runs.1.poll());
+    clock.advance(secs(4));
+    increment.register_player_move(PlayerColor::Black, Position::new(7, 7)).await.unwrap();
+    delay.register_player_move(PlayerColor::Black, Position::new(7, 7)).await.unwrap();
+
+    assert_eq!(increment.get_time_left(PlayerColor::Black).await.main_time, secs(19));
+    assert_eq!(delay.get_time_left(PlayerColor::Black).await.main_time, secs(19));
+
+    clock.advance(secs(2));
+    increment.register_player_move(PlayerColor::White, Position::new(7, 8)).await.unwrap();
+    delay.register_player_move(PlayerColor::White, Position::new(7, 8)).await.unwrap();
+
+    assert_eq!(increment.get_time_left(PlayerColor::White).await.main_time, secs(21));
+    assert_eq!(delay.get_time_left(PlayerColor::White).await.main_time, secs(20));
+}
+
+#[tokio::test]
+async fn engine_swaps_clocks_between_players() {
+    let clock = VirtualClock::new();
+    let mut engine = engine(TimeControl::None, clock.clone());
+    let watcher = engine.clone();
+    let mut run = task::spawn(watcher.run_timers());
+
+    assert_pending!(run.poll());
+    clock.advance(secs(4));
+    engine.register_player_move(PlayerColor::Black, Position::new(7, 7)).await.unwrap();
+    assert_pending!(run.poll());
+    clock.advance(secs(3));
+    engine.swap_clocks().await;
+    assert_pending!(run.poll());
+
+    assert_eq!(engine.get_time_left(PlayerColor::Black).await.main_time, secs(17));
+    assert_eq!(engine.get_time_left(PlayerColor::White).await.main_time, secs(16));
+
+    // The clock of the player to move keeps running.
+    clock.advance(secs(2));
+    assert_pending!(run.poll());
+    assert_eq!(engine.get_time_left(PlayerColor::Black).await.main_time, secs(17));
+    assert_eq!(engine.get_time_left(PlayerColor::White).await.main_time, secs(14));
+}
@@ -0,0 +1,95 @@
+//! Tests of the Zobrist keys identifying board positions.
+
+use gomokurs_game_engine::domain::game_engine::models::*;
+use gomokurs_game_engine::domain::game_engine::models::zobrist::{empty_board_key, stone_key};
+
+const SIZE: BoardSize = BoardSize { x: 15, y: 15 };
+
+/// Moves scattered enough for neither player to make a row of five.
+const MOVES: [(u8, u8); 8] = [(7, 7), (7, 8), (3, 11), (12, 2), (0, 0), (14, 14), (9, 4), (4, 9)];
+
+fn positions() -> impl Iterator<Item = Position>
+{
+    MOVES.into_iter().map(|(x, y)| Position::new(x, y))
+}
+
+#[test]
+fn key_combines_the_empty_board_and_every_stone() {
+    let mut game = Game::new(SIZE, Rules::default(), None).unwrap();
+    let mut expected = empty_board_key(SIZE);
+
+    assert_eq!(game.board().key(), expected);
+
+    for position in positions() {
+        expected ^= stone_key(game.turn_player(), position);
+        game.play(position).unwrap();
+
+        assert_eq!(game.board().key(), expected);
+    }
+}
+
+#[test]
+fn undo_restores_the_key() {
+    let mut game = Game::new(SIZE, Rules::default(), None).unwrap();
+    let mut keys = vec![game.board().key()];
+
+    for position in positions() {
+        game.play(position).unwrap();
+        keys.push(game.board().key());
+    }
+
+    while game.undo().is_ok() {
+        keys.pop();
+        assert_eq!(Some(&game.board().key()), keys.last());
+    }
+    assert_eq!(keys, [empty_board_key(SIZE)]);
+}
+
+#[test]
+fn key_does_not_depend_on_the_move_order() {
+    let stones: Vec<(Position, CellStatus)> = positions()
+        .zip([CellStatus::Black, CellStatus::White].into_iter().cycle())
+        .collect();
+
+    let mut board = Board::new(SIZE).unwrap();
+    for (position, status) in &stones {
+        board.set_cell(*position, *status).unwrap();
+    }
+
+    let mut reversed = Board::new(SIZE).unwrap();
+    for (position, status) in stones.iter().rev() {
+        reversed.set_cell(*position, *status).unwrap();
+    }
+
+    assert_eq!(board.key(), reversed.key());
+}
+
+#[test]
+fn key_tells_colors_and_sizes_apart() {
+    let position = Position::new(7, 7);
+    let mut black = Board::new(SIZE).unwrap();
+    let mut white = Board::new(SIZE).unwrap();
+
+    black.set_cell(position, CellStatus::Black).unwrap();
+    white.set_cell(position, CellStatus::White).unwrap();
+
+    assert_ne!(black.key(), white.key());
+    assert_ne!(empty_board_key(SIZE), empty_board_key(BoardSize { x: 15, y: 16 }));
+    assert_ne!(empty_board_key(BoardSize { x: 15, y: 16 }), empty_board_key(BoardSize { x: 16, y: 15 }));
+}
+
+#[test]
+fn clearing_the_board_restores_the_empty_key() {
+    let mut board = Board::new(SIZE).unwrap();
+
+    for position in positions() {
+        board.set_cell(position, CellStatus::Black).unwrap();
+    }
+    board.clear_cell(Position::new(7, 7)).unwrap();
+    assert_eq!(board.key(), Board::new(SIZE).unwrap().key() ^ positions().skip(1).fold(0, |key, position| {
+        key ^ stone_key(PlayerColor::Black, position)
+    }));
+
+    board.clear();
+    assert_eq!(board.key(), empty_board_key(SIZE));
+}